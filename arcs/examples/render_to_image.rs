@@ -4,7 +4,7 @@ use arcs::{
         Viewport,
     },
     window::Window,
-    Length, Line, Point,
+    Angle, Length, Line, Point,
 };
 use euclid::{Scale, Size2D};
 use image::RgbaImage;
@@ -33,10 +33,10 @@ fn main() {
     // add a green dot to the world
     world
         .create_entity()
-        .with(DrawingObject {
-            geometry: Geometry::Point(Point::new(20.0, 0.0)),
+        .with(DrawingObject::new(
+            Geometry::Point(Point::new(20.0, 0.0)),
             layer,
-        })
+        ))
         .with(PointStyle {
             radius: Dimension::Pixels(50.0),
             colour: Color::rgb8(0, 0xff, 0),
@@ -53,13 +53,14 @@ fn main() {
 
         world
             .create_entity()
-            .with(DrawingObject {
-                geometry: Geometry::Line(Line::new(start, end)),
+            .with(DrawingObject::new(
+                Geometry::Line(Line::new(start, end)),
                 layer,
-            })
+            ))
             .with(LineStyle {
                 width: Dimension::DrawingUnits(Length::new(5.0)),
                 stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
             })
             .build();
     }
@@ -71,6 +72,8 @@ fn main() {
     *window.viewport_mut(&mut world.write_storage()) = Viewport {
         centre: Point::zero(),
         pixels_per_drawing_unit: Scale::new(5.0),
+        rotation: Angle::zero(),
+        y_up: true,
     };
     window
         .style_mut(&mut world.write_storage())
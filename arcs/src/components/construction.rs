@@ -0,0 +1,12 @@
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// An empty [`Component`] used to mark an [`Entity`] as construction
+/// geometry.
+///
+/// Construction geometry is only there to help while drafting (e.g. a
+/// reference line to line things up against), so it gets drawn with a dashed
+/// line and shouldn't be treated as part of the finished drawing.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Component)]
+#[storage(NullStorage)]
+pub struct Construction;
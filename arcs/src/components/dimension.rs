@@ -1,5 +1,12 @@
 use crate::{CanvasSpace, DrawingSpace, Length};
-use euclid::Scale;
+use euclid::{Scale, Size2D};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+/// The number of millimetres in an inch.
+const MILLIMETRES_PER_INCH: f64 = 25.4;
 
 /// A dimension on the canvas.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -10,9 +17,31 @@ pub enum Dimension {
     /// A "real" dimension defined in *Drawing Space*, which should be scaled
     /// appropriately when we zoom.
     DrawingUnits(Length),
+    /// A fraction of the window's smaller dimension (e.g. `Percent(0.1)` is
+    /// 10% of the window's width or height, whichever is smaller).
+    ///
+    /// This keeps overlays like legends and scale bars proportional to the
+    /// window, regardless of its resolution.
+    Percent(f64),
 }
 
 impl Dimension {
+    /// Create a [`Dimension`] representing a real-world length in
+    /// millimetres.
+    pub fn millimetres(value: f64, unit: DrawingUnit) -> Dimension {
+        Dimension::DrawingUnits(Length::new(value / unit.millimetres()))
+    }
+
+    /// Create a [`Dimension`] representing a real-world length in inches.
+    pub fn inches(value: f64, unit: DrawingUnit) -> Dimension {
+        Dimension::millimetres(value * MILLIMETRES_PER_INCH, unit)
+    }
+
+    /// How many pixels this [`Dimension`] is at a particular zoom level.
+    ///
+    /// A [`Dimension::Percent`] depends on the window size, so it comes out
+    /// as `NaN`. Use [`Dimension::in_pixels_for_window()`] when that is a
+    /// possibility.
     pub fn in_pixels(
         self,
         pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
@@ -22,10 +51,267 @@ impl Dimension {
             Dimension::DrawingUnits(length) => {
                 length.get() * pixels_per_drawing_unit.get()
             },
+            Dimension::Percent(_) => f64::NAN,
+        }
+    }
+
+    /// How many pixels this [`Dimension`] is at a particular zoom level, in
+    /// a window of a particular size.
+    pub fn in_pixels_for_window(
+        self,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+        window_size: Size2D<f64, CanvasSpace>,
+    ) -> f64 {
+        match self {
+            Dimension::Percent(fraction) => {
+                fraction * f64::min(window_size.width, window_size.height)
+            },
+            _ => self.in_pixels(pixels_per_drawing_unit),
         }
     }
+
+    /// Compare two [`Dimension`]s by how many pixels they would take up at a
+    /// particular zoom level.
+    ///
+    /// This is handy for things like picking the wider of two line widths
+    /// when one is in [`Dimension::Pixels`] and the other is in
+    /// [`Dimension::DrawingUnits`]. A [`Dimension::Percent`] can't be
+    /// resolved without the window size and sorts after everything else, so
+    /// prefer [`Dimension::cmp_in_pixels_for_window()`] when it may come up.
+    pub fn cmp_in_pixels(
+        self,
+        other: Dimension,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+    ) -> Ordering {
+        let this = self.in_pixels(pixels_per_drawing_unit);
+        let other = other.in_pixels(pixels_per_drawing_unit);
+
+        this.total_cmp(&other)
+    }
+
+    /// Compare two [`Dimension`]s by how many pixels they would take up at a
+    /// particular zoom level, in a window of a particular size.
+    pub fn cmp_in_pixels_for_window(
+        self,
+        other: Dimension,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+        window_size: Size2D<f64, CanvasSpace>,
+    ) -> Ordering {
+        let this =
+            self.in_pixels_for_window(pixels_per_drawing_unit, window_size);
+        let other =
+            other.in_pixels_for_window(pixels_per_drawing_unit, window_size);
+
+        this.total_cmp(&other)
+    }
+
+    /// The inverse of [`Dimension::in_pixels()`], figure out how many drawing
+    /// units this [`Dimension`] corresponds to at a particular zoom level.
+    ///
+    /// As with [`Dimension::in_pixels()`], a [`Dimension::Percent`] comes out
+    /// as `NaN`.
+    pub fn in_drawing_units(
+        self,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+    ) -> Length {
+        match self {
+            Dimension::Pixels(_) | Dimension::Percent(_) => Length::new(
+                self.in_pixels(pixels_per_drawing_unit)
+                    / pixels_per_drawing_unit.get(),
+            ),
+            Dimension::DrawingUnits(length) => length,
+        }
+    }
+
+    /// The inverse of [`Dimension::in_pixels_for_window()`].
+    pub fn in_drawing_units_for_window(
+        self,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+        window_size: Size2D<f64, CanvasSpace>,
+    ) -> Length {
+        match self {
+            Dimension::Percent(_) => Length::new(
+                self.in_pixels_for_window(pixels_per_drawing_unit, window_size)
+                    / pixels_per_drawing_unit.get(),
+            ),
+            _ => self.in_drawing_units(pixels_per_drawing_unit),
+        }
+    }
+
+    /// Convert this [`Dimension`] to a real-world length in millimetres.
+    pub fn in_millimetres(
+        self,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+        unit: DrawingUnit,
+    ) -> f64 {
+        self.in_drawing_units(pixels_per_drawing_unit).get()
+            * unit.millimetres()
+    }
+
+    /// Convert this [`Dimension`] to a real-world length in inches.
+    pub fn in_inches(
+        self,
+        pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+        unit: DrawingUnit,
+    ) -> f64 {
+        self.in_millimetres(pixels_per_drawing_unit, unit)
+            / MILLIMETRES_PER_INCH
+    }
 }
 
 impl Default for Dimension {
     fn default() -> Dimension { Dimension::Pixels(1.0) }
 }
+
+/// The real-world length represented by a single drawing unit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DrawingUnit {
+    #[default]
+    Millimetres,
+    Metres,
+    Inches,
+}
+
+impl DrawingUnit {
+    /// How many millimetres a single drawing unit represents.
+    pub fn millimetres(self) -> f64 {
+        match self {
+            DrawingUnit::Millimetres => 1.0,
+            DrawingUnit::Metres => 1000.0,
+            DrawingUnit::Inches => MILLIMETRES_PER_INCH,
+        }
+    }
+
+    /// The abbreviation normally used when displaying this unit.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            DrawingUnit::Millimetres => "mm",
+            DrawingUnit::Metres => "m",
+            DrawingUnit::Inches => "in",
+        }
+    }
+
+    /// Get a displayable version of a [`Length`], with units (e.g.
+    /// `"12.50 mm"`).
+    pub fn display(self, length: Length) -> impl Display {
+        DisplayLength { unit: self, length }
+    }
+}
+
+struct DisplayLength {
+    unit: DrawingUnit,
+    length: Length,
+}
+
+impl Display for DisplayLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} {}", self.length.get(), self.unit.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    #[test]
+    fn millimetres_to_inches_and_back() {
+        let ppu = Scale::new(4.0);
+        let unit = DrawingUnit::Millimetres;
+
+        let dimension = Dimension::millimetres(50.8, unit);
+
+        assert!(dimension.in_inches(ppu, unit).approx_eq(&2.0));
+        let round_tripped =
+            Dimension::inches(dimension.in_inches(ppu, unit), unit);
+        assert!(round_tripped
+            .in_millimetres(ppu, unit)
+            .approx_eq(&dimension.in_millimetres(ppu, unit)));
+    }
+
+    #[test]
+    fn inches_to_millimetres_and_back_with_metre_drawing_units() {
+        let ppu = Scale::new(1.0);
+        let unit = DrawingUnit::Metres;
+
+        let dimension = Dimension::inches(10.0, unit);
+
+        assert_eq!(dimension, Dimension::DrawingUnits(Length::new(0.254)));
+        assert!(dimension.in_millimetres(ppu, unit).approx_eq(&254.0));
+        let round_tripped =
+            Dimension::millimetres(dimension.in_millimetres(ppu, unit), unit);
+        assert!(round_tripped.in_inches(ppu, unit).approx_eq(&10.0));
+    }
+
+    #[test]
+    fn pixels_to_drawing_units() {
+        let ppu = Scale::new(4.0);
+
+        let got = Dimension::Pixels(10.0).in_drawing_units(ppu);
+
+        assert_eq!(got, Length::new(2.5));
+        assert_eq!(Dimension::DrawingUnits(got).in_pixels(ppu), 10.0);
+    }
+
+    #[test]
+    fn compare_dimensions_at_a_zoom_level() {
+        let pixels = Dimension::Pixels(10.0);
+        let drawing_units = Dimension::DrawingUnits(Length::new(3.0));
+
+        // 3 drawing units is 12px when zoomed in
+        assert_eq!(
+            pixels.cmp_in_pixels(drawing_units, Scale::new(4.0)),
+            Ordering::Less
+        );
+        // but only 6px when zoomed out
+        assert_eq!(
+            pixels.cmp_in_pixels(drawing_units, Scale::new(2.0)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn compare_percentages_in_a_window() {
+        let window_size = Size2D::new(800.0, 600.0);
+
+        assert_eq!(
+            Dimension::Percent(0.1).cmp_in_pixels_for_window(
+                Dimension::Pixels(60.0),
+                Scale::new(1.0),
+                window_size
+            ),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn percentages_use_the_smaller_side_of_the_window() {
+        let ppu = Scale::new(4.0);
+        let window_size = Size2D::new(800.0, 600.0);
+
+        let got = Dimension::Percent(0.1);
+
+        assert!(got.in_pixels_for_window(ppu, window_size).approx_eq(&60.0));
+        assert!(got
+            .in_drawing_units_for_window(ppu, window_size)
+            .get()
+            .approx_eq(&15.0));
+    }
+
+    #[test]
+    fn percentages_need_a_window() {
+        let ppu = Scale::new(4.0);
+
+        let got = Dimension::Percent(0.1);
+
+        assert!(got.in_pixels(ppu).is_nan());
+        assert!(got.in_drawing_units(ppu).get().is_nan());
+    }
+
+    #[test]
+    fn display_a_length_with_units() {
+        let got = DrawingUnit::Millimetres.display(Length::new(12.5));
+
+        assert_eq!(got.to_string(), "12.50 mm");
+    }
+}
@@ -0,0 +1,19 @@
+use specs::prelude::*;
+use specs_derive::Component;
+
+#[allow(unused_imports)] // for rustdoc links
+use crate::components::Layer;
+
+/// Controls the order objects on the same [`Layer`] are drawn in.
+///
+/// Objects with a higher priority are drawn later, so they appear on top of
+/// anything with a lower priority. Objects without a [`DrawPriority`] are
+/// treated as having a priority of `0`.
+///
+/// This lets you bring a particular object to the front without moving it to
+/// another [`Layer`].
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Component,
+)]
+#[storage(DenseVecStorage)]
+pub struct DrawPriority(pub i32);
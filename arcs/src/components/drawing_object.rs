@@ -1,8 +1,16 @@
 use crate::{
-    algorithms::{Bounded, Closest, ClosestPoint, Translate},
-    Arc, BoundingBox, DrawingSpace, Line, Point, Vector,
+    algorithms::{
+        AffineTransformable, Approximate, ArrayPattern, Bounded, Closest,
+        ClosestPoint,
+    },
+    components::Selected,
+    Arc, BoundingBox, CanvasSpace, DrawingSpace, EllipticalArc, Length, Line,
+    Point, Transform, Vector,
 };
+use euclid::Transform2D;
+use kurbo::{BezPath, Circle, Shape};
 use specs::prelude::*;
+use std::sync::{PoisonError, RwLock};
 
 // for rustdoc links
 #[allow(unused_imports)]
@@ -11,9 +19,12 @@ use crate::components::Layer;
 /// Something which can be drawn on the screen.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DrawingObject {
-    pub geometry: Geometry,
+    geometry: Geometry,
     /// The [`Layer`] this [`DrawingObject`] is attached to.
     pub layer: Entity,
+    /// Incremented every time the [`Geometry`] may have been changed.
+    geometry_version: GeometryVersion,
+    bounds: BoundsCache,
 }
 
 impl Component for DrawingObject {
@@ -26,7 +37,171 @@ impl Component for DrawingObject {
 pub enum Geometry {
     Line(Line),
     Arc(Arc),
+    /// An ellipse, or part of one.
+    ///
+    /// Use [`EllipticalArc::full()`] for a complete ellipse.
+    Ellipse(EllipticalArc),
     Point(Point),
+    /// An annotation showing the distance between two points.
+    ///
+    /// The dimension line is drawn parallel to the line from `from` to `to`,
+    /// shifted `offset` drawing units to its left (a negative `offset` will
+    /// shift it to the right).
+    LinearDimension {
+        from: Point,
+        to: Point,
+        offset: Length,
+    },
+}
+
+/// The different kinds of [`Geometry`], without any of their data.
+///
+/// This is useful when you want to compare or filter by kind (e.g. "select
+/// all arcs") without needing to match on every [`Geometry`] variant.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GeometryKind {
+    Line,
+    Arc,
+    Ellipse,
+    Point,
+    LinearDimension,
+}
+
+impl Geometry {
+    /// What kind of [`Geometry`] is this?
+    pub fn kind(&self) -> GeometryKind {
+        match self {
+            Geometry::Line(_) => GeometryKind::Line,
+            Geometry::Arc(_) => GeometryKind::Arc,
+            Geometry::Ellipse(_) => GeometryKind::Ellipse,
+            Geometry::Point(_) => GeometryKind::Point,
+            Geometry::LinearDimension { .. } => GeometryKind::LinearDimension,
+        }
+    }
+
+    /// Calculate the lines making up a [`Geometry::LinearDimension`], in the
+    /// order `(first_extension_line, second_extension_line, dimension_line)`.
+    pub(crate) fn linear_dimension_lines(
+        from: Point,
+        to: Point,
+        offset: Length,
+    ) -> (Line, Line, Line) {
+        let displacement = to - from;
+        let direction = if displacement.length() > 0.0 {
+            displacement.normalize()
+        } else {
+            Vector::new(1.0, 0.0)
+        };
+        let normal = Vector::new(-direction.y, direction.x) * offset.get();
+
+        let first_extension = Line::new(from, from + normal);
+        let second_extension = Line::new(to, to + normal);
+        let dimension_line = Line::new(from + normal, to + normal);
+
+        (first_extension, second_extension, dimension_line)
+    }
+
+    /// Flatten this [`Geometry`] into a polyline, with curves approximated
+    /// so they deviate by at most `tolerance` drawing units.
+    ///
+    /// A [`Geometry::Point`] gives just that point, and a [`Geometry::Line`]
+    /// gives its two end points. A [`Geometry::LinearDimension`] gives its
+    /// first extension line, the dimension line, then the second extension
+    /// line, which happen to join up end-to-end.
+    pub fn to_polyline(&self, tolerance: f64) -> Vec<Point> {
+        match self {
+            Geometry::Point(point) => vec![*point],
+            Geometry::Line(line) => line.approximate(tolerance).collect(),
+            Geometry::Arc(arc) => arc.approximate(tolerance).collect(),
+            Geometry::Ellipse(arc) => arc.approximate(tolerance).collect(),
+            Geometry::LinearDimension { from, to, offset } => {
+                let (first, second, _) =
+                    Geometry::linear_dimension_lines(*from, *to, *offset);
+
+                vec![first.start, first.end, second.end, second.start]
+            },
+        }
+    }
+
+    /// Convert this [`Geometry`] to a [`BezPath`] in *Canvas Space*, so it
+    /// can be drawn by any [`piet::RenderContext`].
+    ///
+    /// Curves are approximated so they deviate by at most `tolerance` pixels.
+    /// Because this happens after transforming to canvas space, zooming in
+    /// won't make arcs look faceted.
+    ///
+    /// Points are drawn as a small circle, and a [`Geometry::LinearDimension`]
+    /// becomes its extension and dimension lines (without arrowheads or a
+    /// label).
+    pub fn to_bez_path(
+        &self,
+        transform: &Transform2D<f64, DrawingSpace, CanvasSpace>,
+        tolerance: f64,
+    ) -> BezPath {
+        /// The radius used when drawing points, in pixels.
+        const POINT_RADIUS: f64 = 1.0;
+
+        let to_kurbo = |point: Point| {
+            let point = transform.transform_point(point);
+            kurbo::Point::new(point.x, point.y)
+        };
+        let line_to_path = |path: &mut BezPath, line: &Line| {
+            path.move_to(to_kurbo(line.start));
+            path.line_to(to_kurbo(line.end));
+        };
+
+        match self {
+            Geometry::Point(point) => {
+                Circle::new(to_kurbo(*point), POINT_RADIUS)
+                    .into_bez_path(tolerance)
+            },
+            Geometry::Line(line) => {
+                let mut path = BezPath::new();
+                line_to_path(&mut path, line);
+                path
+            },
+            Geometry::Arc(arc) => {
+                let arc = arc.transformed(transform.to_untyped());
+
+                kurbo::Arc {
+                    center: kurbo::Point::new(arc.centre().x, arc.centre().y),
+                    radii: kurbo::Vec2::new(arc.radius(), arc.radius()),
+                    start_angle: arc.start_angle().radians,
+                    sweep_angle: arc.sweep_angle().radians,
+                    x_rotation: 0.0,
+                }
+                .into_bez_path(tolerance)
+            },
+            Geometry::Ellipse(arc) => {
+                let arc = arc.transformed(transform.to_untyped());
+                let ellipse = arc.ellipse();
+
+                kurbo::Arc {
+                    center: kurbo::Point::new(arc.centre().x, arc.centre().y),
+                    radii: kurbo::Vec2::new(
+                        ellipse.major_radius(),
+                        ellipse.minor_radius(),
+                    ),
+                    start_angle: arc.start_angle().radians,
+                    sweep_angle: arc.sweep_angle().radians,
+                    x_rotation: ellipse.rotation().radians,
+                }
+                .into_bez_path(tolerance)
+            },
+            Geometry::LinearDimension { from, to, offset } => {
+                let (first, second, dimension_line) =
+                    Geometry::linear_dimension_lines(*from, *to, *offset);
+                let mut path = BezPath::new();
+
+                for line in &[first, second, dimension_line] {
+                    line_to_path(&mut path, line);
+                }
+
+                path
+            },
+        }
+    }
 }
 
 impl ClosestPoint<DrawingSpace> for Geometry {
@@ -35,8 +210,36 @@ impl ClosestPoint<DrawingSpace> for Geometry {
             Geometry::Point(p) => p.closest_point(target),
             Geometry::Line(l) => l.closest_point(target),
             Geometry::Arc(a) => a.closest_point(target),
+            Geometry::Ellipse(e) => e.closest_point(target),
+            Geometry::LinearDimension { from, to, offset } => {
+                let (first, second, dimension_line) =
+                    Geometry::linear_dimension_lines(*from, *to, *offset);
+
+                closest_of(target, &[first, second, dimension_line])
+            },
+        }
+    }
+}
+
+/// Find the closest point to `target` across several [`ClosestPoint`] items.
+fn closest_of<C>(target: Point, items: &[C]) -> Closest<DrawingSpace>
+where
+    C: ClosestPoint<DrawingSpace>,
+{
+    let mut best: Option<(f64, Closest<DrawingSpace>)> = None;
+
+    for item in items {
+        let closest = item.closest_point(target);
+        let distance = closest.distance_to(target).unwrap_or(f64::INFINITY);
+
+        match best {
+            Some((best_distance, _)) if best_distance <= distance => {},
+            _ => best = Some((distance, closest)),
         }
     }
+
+    best.map(|(_, closest)| closest)
+        .unwrap_or(Closest::Infinite)
 }
 
 impl ClosestPoint<DrawingSpace> for DrawingObject {
@@ -50,23 +253,761 @@ impl Bounded<DrawingSpace> for Geometry {
         match self {
             Geometry::Line(line) => line.bounding_box(),
             Geometry::Arc(arc) => arc.bounding_box(),
+            Geometry::Ellipse(arc) => arc.bounding_box(),
             Geometry::Point(point) => point.bounding_box(),
+            Geometry::LinearDimension { from, to, offset } => {
+                let (first, second, _) =
+                    Geometry::linear_dimension_lines(*from, *to, *offset);
+
+                BoundingBox::around([first, second])
+                    .expect("There are always two extension lines")
+            },
         }
     }
 }
 
-impl Translate<DrawingSpace> for Geometry {
-    fn translate(&mut self, displacement: Vector) {
+impl crate::algorithms::Length for Geometry {
+    /// The length of a line, arc, or ellipse.
+    ///
+    /// Points and dimensions aren't something you could measure along, so
+    /// they have a length of zero.
+    fn length(&self) -> f64 {
         match self {
-            Geometry::Point(ref mut point) => point.translate(displacement),
-            Geometry::Line(ref mut line) => line.translate(displacement),
-            Geometry::Arc(ref mut arc) => arc.translate(displacement),
+            Geometry::Line(line) => line.length(),
+            Geometry::Arc(arc) => arc.length(),
+            Geometry::Ellipse(arc) => arc.length(),
+            Geometry::Point(_) | Geometry::LinearDimension { .. } => 0.0,
+        }
+    }
+}
+
+impl AffineTransformable for Geometry {
+    /// Transform the [`Geometry`].
+    ///
+    /// An [`Arc`] which gets stretched or skewed is no longer circular, so it
+    /// is replaced by the equivalent [`Geometry::Ellipse`].
+    fn transform(&mut self, transform: euclid::default::Transform2D<f64>) {
+        if let Geometry::Arc(arc) = *self {
+            if !is_similarity(&transform) {
+                *self = Geometry::Ellipse(
+                    EllipticalArc::from(arc).transformed(transform),
+                );
+                return;
+            }
+        }
+
+        match self {
+            Geometry::Point(ref mut point) => point.transform(transform),
+            Geometry::Line(ref mut line) => line.transform(transform),
+            Geometry::Arc(ref mut arc) => arc.transform(transform),
+            Geometry::Ellipse(ref mut arc) => arc.transform(transform),
+            Geometry::LinearDimension {
+                ref mut from,
+                ref mut to,
+                ref mut offset,
+            } => {
+                from.transform(transform);
+                to.transform(transform);
+
+                // the offset scales with the drawing, and mirroring moves the
+                // dimension line to the other side
+                let determinant = transform.determinant();
+                *offset *= determinant.abs().sqrt();
+                if determinant < 0.0 {
+                    *offset = -*offset;
+                }
+            },
+        }
+    }
+}
+
+/// Does this transform keep circles circular (i.e. it only rotates, mirrors,
+/// translates, and scales uniformly)?
+fn is_similarity(transform: &euclid::default::Transform2D<f64>) -> bool {
+    let x_axis = transform.m11 * transform.m11 + transform.m12 * transform.m12;
+    let y_axis = transform.m21 * transform.m21 + transform.m22 * transform.m22;
+    let skew = transform.m11 * transform.m21 + transform.m12 * transform.m22;
+    let tolerance = 1e-9 * x_axis.max(y_axis);
+
+    (x_axis - y_axis).abs() <= tolerance && skew.abs() <= tolerance
+}
+
+impl AffineTransformable for DrawingObject {
+    fn transform(&mut self, transform: euclid::default::Transform2D<f64>) {
+        self.geometry_mut().transform(transform);
+    }
+}
+
+/// A counter which is bumped every time a [`DrawingObject`]'s [`Geometry`]
+/// is handed out mutably.
+///
+/// It's only used for cache invalidation, so it doesn't take part in
+/// equality checks.
+#[derive(Debug, Default, Copy, Clone)]
+struct GeometryVersion(u64);
+
+impl PartialEq for GeometryVersion {
+    fn eq(&self, _other: &GeometryVersion) -> bool { true }
+}
+
+/// The most recently calculated [`DrawingObject::bounds()`], and the
+/// [`GeometryVersion`] they were calculated for.
+///
+/// This uses a lock so the renderer can read [`DrawingObject`]s from
+/// multiple threads (see the `rayon` feature).
+#[derive(Debug, Default)]
+struct BoundsCache(RwLock<Option<(u64, BoundingBox<DrawingSpace>)>>);
+
+impl BoundsCache {
+    fn get(&self) -> Option<(u64, BoundingBox<DrawingSpace>)> {
+        *self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set(&self, version: u64, bounds: BoundingBox<DrawingSpace>) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) =
+            Some((version, bounds));
+    }
+}
+
+impl Clone for BoundsCache {
+    fn clone(&self) -> BoundsCache { BoundsCache(RwLock::new(self.get())) }
+}
+
+impl PartialEq for BoundsCache {
+    fn eq(&self, _other: &BoundsCache) -> bool { true }
+}
+
+impl Bounded<DrawingSpace> for DrawingObject {
+    fn bounding_box(&self) -> BoundingBox<DrawingSpace> { self.bounds() }
+}
+
+impl DrawingObject {
+    pub fn new(geometry: Geometry, layer: Entity) -> DrawingObject {
+        DrawingObject {
+            geometry,
+            layer,
+            geometry_version: GeometryVersion::default(),
+            bounds: BoundsCache::default(),
+        }
+    }
+
+    /// The [`Geometry`] being drawn.
+    pub fn geometry(&self) -> &Geometry { &self.geometry }
+
+    /// Get a mutable reference to the [`Geometry`], invalidating any cached
+    /// [`DrawingObject::bounds()`].
+    pub fn geometry_mut(&mut self) -> &mut Geometry {
+        self.geometry_version.0 += 1;
+        &mut self.geometry
+    }
+
+    /// Replace the [`Geometry`], invalidating any cached
+    /// [`DrawingObject::bounds()`].
+    pub fn set_geometry(&mut self, geometry: Geometry) {
+        *self.geometry_mut() = geometry;
+    }
+
+    /// The area this [`DrawingObject`] covers.
+    ///
+    /// The result is cached until the [`Geometry`] is changed, so this is
+    /// cheap to call every frame. [`crate::systems::SyncBounds`] and the
+    /// renderer both go through this method.
+    pub fn bounds(&self) -> BoundingBox<DrawingSpace> {
+        let version = self.geometry_version.0;
+
+        if let Some((cached_version, bounds)) = self.bounds.get() {
+            if cached_version == version {
+                return bounds;
+            }
+        }
+
+        let bounds = self.geometry.bounding_box();
+        self.bounds.set(version, bounds);
+
+        bounds
+    }
+
+    /// Apply a [`Transform`] to every [`Selected`] [`DrawingObject`].
+    ///
+    /// This gives tools which rotate, scale, or mirror the selection a single
+    /// code path.
+    pub fn transform_selection(
+        transform: Transform,
+        selected: &ReadStorage<'_, Selected>,
+        drawing_objects: &mut WriteStorage<'_, DrawingObject>,
+    ) {
+        let transform = transform.to_untyped();
+
+        for (drawing_object, _) in (drawing_objects, selected).join() {
+            drawing_object.transform(transform);
         }
     }
+
+    /// Make an array of copies of every [`Selected`] [`DrawingObject`],
+    /// returning the newly created entities.
+    ///
+    /// The selection is copied as a whole, using the centre of its bounding
+    /// box as the reference point for [`ArrayPattern::Polar`] arrays. The
+    /// copies are on the same [`Layer`] as the original but aren't selected,
+    /// and only the [`DrawingObject`] is copied (not any styles).
+    pub fn array_selection(
+        pattern: &ArrayPattern<DrawingSpace>,
+        entities: &Entities<'_>,
+        selected: &ReadStorage<'_, Selected>,
+        drawing_objects: &mut WriteStorage<'_, DrawingObject>,
+    ) -> Vec<Entity> {
+        let originals: Vec<DrawingObject> = (&*drawing_objects, selected)
+            .join()
+            .map(|(drawing_object, _)| drawing_object.clone())
+            .collect();
+        let bounds = match BoundingBox::around(&originals) {
+            Some(bounds) => bounds,
+            None => return Vec::new(),
+        };
+        let reference = bounds.bottom_left() + bounds.diagonal() / 2.0;
+        let transforms = pattern.transforms(reference);
+        let mut copies = Vec::new();
+
+        for original in originals {
+            for &transform in &transforms {
+                let copy = original.transformed(transform.to_untyped());
+                let ent = entities.create();
+                drawing_objects
+                    .insert(ent, copy)
+                    .expect("The entity was just created");
+                copies.push(ent);
+            }
+        }
+
+        copies
+    }
 }
 
-impl Translate<DrawingSpace> for DrawingObject {
-    fn translate(&mut self, displacement: Vector) {
-        self.geometry.translate(displacement);
+/// The area covered by every [`DrawingObject`] in the drawing, or `None` if
+/// there is nothing to draw.
+///
+/// The cached [`BoundingBox`] component maintained by
+/// [`crate::systems::SyncBounds`] is used where available, falling back to
+/// calculating the bounds from scratch.
+pub fn drawing_bounds(world: &World) -> Option<BoundingBox<DrawingSpace>> {
+    let drawing_objects = world.read_storage::<DrawingObject>();
+    let bounds = world.read_storage::<BoundingBox<DrawingSpace>>();
+
+    BoundingBox::around(
+        (&drawing_objects, bounds.maybe())
+            .join()
+            .map(|(obj, cached)| {
+                cached.copied().unwrap_or_else(|| obj.bounds())
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{ScaleNonUniform, Translate},
+        Angle, Ellipse,
+    };
+    use euclid::approxeq::ApproxEq;
+    use kurbo::PathEl;
+
+    #[test]
+    fn linear_dimension_is_offset_to_the_left() {
+        let from = Point::new(0.0, 0.0);
+        let to = Point::new(10.0, 0.0);
+
+        let (first, second, dimension_line) =
+            Geometry::linear_dimension_lines(from, to, Length::new(5.0));
+
+        assert_eq!(first, Line::new(from, Point::new(0.0, 5.0)));
+        assert_eq!(second, Line::new(to, Point::new(10.0, 5.0)));
+        assert_eq!(
+            dimension_line,
+            Line::new(Point::new(0.0, 5.0), Point::new(10.0, 5.0))
+        );
+    }
+
+    #[test]
+    fn geometry_kinds() {
+        let line =
+            Geometry::Line(Line::new(Point::zero(), Point::new(1.0, 0.0)));
+        let other_line =
+            Geometry::Line(Line::new(Point::new(5.0, 5.0), Point::zero()));
+        let point = Geometry::Point(Point::zero());
+
+        assert_eq!(line.kind(), GeometryKind::Line);
+        assert_eq!(line.kind(), other_line.kind());
+        assert_eq!(point.kind(), GeometryKind::Point);
+        assert_ne!(line.kind(), point.kind());
+    }
+
+    #[test]
+    fn flatten_geometry_to_polylines() {
+        let point = Point::new(1.0, 2.0);
+        assert_eq!(Geometry::Point(point).to_polyline(0.1), vec![point]);
+
+        let line = Line::new(point, Point::new(3.0, 4.0));
+        assert_eq!(
+            Geometry::Line(line).to_polyline(0.1),
+            vec![line.start, line.end]
+        );
+
+        let arc = Arc::from_centre_radius(
+            Point::zero(),
+            10.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let got = Geometry::Arc(arc).to_polyline(0.1);
+        assert!(got.len() > 2);
+        assert_eq!(got[0], arc.start());
+        assert!(got.last().unwrap().approx_eq(&arc.end()));
+        for point in &got {
+            assert!((point.to_vector().length() - 10.0).abs() < 1e-6);
+        }
+
+        let dimension = Geometry::LinearDimension {
+            from: Point::new(0.0, 0.0),
+            to: Point::new(10.0, 0.0),
+            offset: Length::new(5.0),
+        };
+        assert_eq!(
+            dimension.to_polyline(0.1),
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 5.0),
+                Point::new(10.0, 5.0),
+                Point::new(10.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn linear_dimension_bounds_include_the_dimension_line() {
+        let geometry = Geometry::LinearDimension {
+            from: Point::new(0.0, 0.0),
+            to: Point::new(10.0, 0.0),
+            offset: Length::new(-5.0),
+        };
+
+        let got = geometry.bounding_box();
+
+        assert_eq!(
+            got,
+            BoundingBox::new(Point::new(0.0, -5.0), Point::new(10.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn closest_point_on_a_linear_dimension() {
+        let geometry = Geometry::LinearDimension {
+            from: Point::new(0.0, 0.0),
+            to: Point::new(10.0, 0.0),
+            offset: Length::new(5.0),
+        };
+
+        let got = geometry.closest_point(Point::new(4.0, 6.0));
+
+        assert_eq!(got, Closest::One(Point::new(4.0, 5.0)));
+    }
+
+    #[test]
+    fn translate_every_kind_of_geometry() {
+        use crate::algorithms::Translate;
+
+        let displacement = Vector::new(3.0, -1.0);
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 1.0),
+            5.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let inputs = vec![
+            (
+                Geometry::Point(Point::new(1.0, 2.0)),
+                Geometry::Point(Point::new(4.0, 1.0)),
+            ),
+            (
+                Geometry::Line(Line::new(Point::zero(), Point::new(1.0, 1.0))),
+                Geometry::Line(Line::new(
+                    Point::new(3.0, -1.0),
+                    Point::new(4.0, 0.0),
+                )),
+            ),
+            (
+                Geometry::Arc(arc),
+                Geometry::Arc(Arc::from_centre_radius(
+                    Point::new(4.0, 0.0),
+                    5.0,
+                    Angle::zero(),
+                    Angle::frac_pi_2(),
+                )),
+            ),
+            (
+                Geometry::LinearDimension {
+                    from: Point::zero(),
+                    to: Point::new(10.0, 0.0),
+                    offset: Length::new(2.0),
+                },
+                Geometry::LinearDimension {
+                    from: Point::new(3.0, -1.0),
+                    to: Point::new(13.0, -1.0),
+                    offset: Length::new(2.0),
+                },
+            ),
+        ];
+
+        for (geometry, expected) in inputs {
+            let got = geometry.translated(displacement);
+
+            assert_eq!(got, expected);
+        }
+    }
+
+    #[test]
+    fn length_of_each_kind_of_geometry() {
+        use crate::algorithms::Length as _;
+
+        let line = Geometry::Line(Line::new(
+            Point::new(1.0, 1.0),
+            Point::new(4.0, 5.0),
+        ));
+        let arc = Geometry::Arc(Arc::from_centre_radius(
+            Point::zero(),
+            2.0,
+            Angle::zero(),
+            -Angle::pi(),
+        ));
+        let dimension = Geometry::LinearDimension {
+            from: Point::zero(),
+            to: Point::new(10.0, 0.0),
+            offset: Length::new(1.0),
+        };
+
+        assert_eq!(line.length(), 5.0);
+        assert_eq!(arc.length(), 2.0 * std::f64::consts::PI);
+        assert_eq!(Geometry::Point(Point::zero()).length(), 0.0);
+        assert_eq!(dimension.length(), 0.0);
+    }
+
+    #[test]
+    fn only_transform_the_selection() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = world.create_entity().build();
+        let original = Line::new(Point::new(1.0, 0.0), Point::new(2.0, 3.0));
+        let geometry = Geometry::Line(original);
+        let selected = world
+            .create_entity()
+            .with(DrawingObject::new(geometry.clone(), layer))
+            .with(Selected)
+            .build();
+        let unselected = world
+            .create_entity()
+            .with(DrawingObject::new(geometry, layer))
+            .build();
+        let mirror = Transform::create_scale(-1.0, 1.0);
+
+        DrawingObject::transform_selection(
+            mirror,
+            &world.read_storage(),
+            &mut world.write_storage(),
+        );
+
+        let drawing_objects = world.read_storage::<DrawingObject>();
+        let mirrored = Line::new(Point::new(-1.0, 0.0), Point::new(-2.0, 3.0));
+        assert_eq!(
+            drawing_objects.get(selected).unwrap().geometry(),
+            &Geometry::Line(mirrored)
+        );
+        assert_eq!(
+            drawing_objects.get(unselected).unwrap().geometry(),
+            &Geometry::Line(original)
+        );
+    }
+
+    #[test]
+    fn stretching_an_arc_turns_it_into_an_ellipse() {
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 1.0),
+            3.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let mut geometry = Geometry::Arc(arc);
+
+        geometry.scale_non_uniform(2.0, 1.0);
+
+        match geometry {
+            Geometry::Ellipse(got) => {
+                let ellipse = got.ellipse();
+                assert!(ellipse.centre().approx_eq(&Point::new(2.0, 1.0)));
+                assert!(ellipse.major_radius().approx_eq(&6.0));
+                assert!(ellipse.minor_radius().approx_eq(&3.0));
+                assert!(got.start().approx_eq(&Point::new(8.0, 1.0)));
+                assert!(got.end().approx_eq(&Point::new(2.0, 4.0)));
+            },
+            other => panic!("Expected an ellipse but found {:?}", other),
+        }
+
+        // rotating and uniformly scaling keeps it circular
+        let mut geometry = Geometry::Arc(arc);
+        geometry.transform(
+            euclid::default::Transform2D::create_rotation(Angle::degrees(30.0))
+                .post_scale(2.0, 2.0),
+        );
+        assert!(matches!(geometry, Geometry::Arc(_)));
+    }
+
+    #[test]
+    fn array_the_selection() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = world.create_entity().build();
+        let point = Point::new(1.0, 2.0);
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(point), layer))
+            .with(Selected)
+            .build();
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .build();
+        let pattern = ArrayPattern::Rectangular {
+            rows: 1,
+            cols: 3,
+            row_spacing: Vector::new(0.0, 1.0),
+            col_spacing: Vector::new(10.0, 0.0),
+        };
+
+        let copies = DrawingObject::array_selection(
+            &pattern,
+            &world.entities(),
+            &world.read_storage(),
+            &mut world.write_storage(),
+        );
+
+        let drawing_objects = world.read_storage::<DrawingObject>();
+        let got: Vec<_> = copies
+            .iter()
+            .map(|&ent| drawing_objects.get(ent).unwrap().geometry().clone())
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                Geometry::Point(Point::new(11.0, 2.0)),
+                Geometry::Point(Point::new(21.0, 2.0)),
+            ]
+        );
+        assert_eq!(drawing_objects.count(), 4);
+    }
+
+    #[test]
+    fn polar_arrays_move_the_selection_as_a_whole() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = world.create_entity().build();
+        for &x in &[-1.0, 1.0] {
+            world
+                .create_entity()
+                .with(DrawingObject::new(
+                    Geometry::Point(Point::new(x, 0.0)),
+                    layer,
+                ))
+                .with(Selected)
+                .build();
+        }
+        let pattern = ArrayPattern::Polar {
+            count: 2,
+            centre: Point::new(10.0, 0.0),
+            total_angle: Angle::degrees(90.0),
+            rotate_copies: false,
+        };
+
+        let copies = DrawingObject::array_selection(
+            &pattern,
+            &world.entities(),
+            &world.read_storage(),
+            &mut world.write_storage(),
+        );
+
+        // the selection's centre moves from (0, 0) to (10, -10), and both
+        // points keep their positions relative to it
+        let drawing_objects = world.read_storage::<DrawingObject>();
+        let got: Vec<_> = copies
+            .iter()
+            .map(|&ent| match drawing_objects.get(ent).unwrap().geometry() {
+                Geometry::Point(point) => *point,
+                other => panic!("Expected a point, found {:?}", other),
+            })
+            .collect();
+        assert_eq!(got.len(), 2);
+        assert!(got[0].approx_eq(&Point::new(9.0, -10.0)));
+        assert!(got[1].approx_eq(&Point::new(11.0, -10.0)));
+    }
+
+    #[test]
+    fn convert_a_line_to_a_path() {
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0));
+        let transform = Transform2D::create_scale(2.0, -1.0);
+
+        let got = Geometry::Line(line).to_bez_path(&transform, 0.1);
+
+        match got.elements() {
+            [PathEl::MoveTo(start), PathEl::LineTo(end)] => {
+                assert_eq!(*start, kurbo::Point::new(2.0, -2.0));
+                assert_eq!(*end, kurbo::Point::new(6.0, -4.0));
+            },
+            other => panic!("Expected a single line, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn arc_paths_start_and_end_in_the_right_place() {
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 1.0),
+            2.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let transform = Transform2D::create_scale(2.0, -2.0);
+
+        let got = Geometry::Arc(arc).to_bez_path(&transform, 0.1);
+
+        let elements = got.elements();
+        match elements.first() {
+            Some(PathEl::MoveTo(start)) => {
+                assert_eq!(*start, kurbo::Point::new(6.0, -2.0))
+            },
+            other => panic!("Expected a move, found {:?}", other),
+        }
+        match elements.last() {
+            Some(PathEl::CurveTo(_, _, end)) => {
+                assert!((end.x - 2.0).abs() < 1e-6);
+                assert!((end.y - -6.0).abs() < 1e-6);
+            },
+            other => panic!("Expected a curve, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn tighter_tolerances_need_more_curves() {
+        let arc = Geometry::Arc(Arc::from_centre_radius(
+            Point::zero(),
+            100.0,
+            Angle::zero(),
+            Angle::two_pi(),
+        ));
+        let transform = Transform2D::identity();
+
+        let rough = arc.to_bez_path(&transform, 10.0);
+        let smooth = arc.to_bez_path(&transform, 1e-6);
+
+        assert!(smooth.elements().len() > rough.elements().len());
+    }
+
+    #[test]
+    fn ellipse_paths_follow_the_ellipse() {
+        let ellipse =
+            Ellipse::new(Point::new(1.0, 1.0), 4.0, 2.0, Angle::zero());
+        let arc =
+            EllipticalArc::new(ellipse, Angle::zero(), Angle::frac_pi_2());
+        let transform = Transform2D::create_scale(2.0, -2.0);
+
+        let got = Geometry::Ellipse(arc).to_bez_path(&transform, 0.1);
+
+        let elements = got.elements();
+        match elements.first() {
+            Some(PathEl::MoveTo(start)) => {
+                assert!((start.x - 10.0).abs() < 1e-6);
+                assert!((start.y - -2.0).abs() < 1e-6);
+            },
+            other => panic!("Expected a move, found {:?}", other),
+        }
+        match elements.last() {
+            Some(PathEl::CurveTo(_, _, end)) => {
+                assert!((end.x - 2.0).abs() < 1e-6);
+                assert!((end.y - -6.0).abs() < 1e-6);
+            },
+            other => panic!("Expected a curve, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn changing_the_geometry_invalidates_the_cached_bounds() {
+        let mut world = World::new();
+        let layer = world.create_entity().build();
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 2.0));
+        let mut obj = DrawingObject::new(Geometry::Line(line), layer);
+        assert_eq!(obj.bounds(), line.bounding_box());
+
+        obj.set_geometry(Geometry::Point(Point::new(5.0, 5.0)));
+        assert_eq!(obj.bounds(), Point::new(5.0, 5.0).bounding_box());
+
+        obj.translate(Vector::new(1.0, 0.0));
+        assert_eq!(obj.bounds(), Point::new(6.0, 5.0).bounding_box());
+
+        if let Geometry::Point(point) = obj.geometry_mut() {
+            *point = Point::new(-1.0, 0.0);
+        }
+        assert_eq!(obj.bounds(), Point::new(-1.0, 0.0).bounding_box());
+    }
+
+    #[test]
+    fn the_bounds_cache_doesnt_affect_equality() {
+        let mut world = World::new();
+        let layer = world.create_entity().build();
+        let geometry = Geometry::Point(Point::new(1.0, 2.0));
+        let mut first = DrawingObject::new(geometry.clone(), layer);
+        let _ = first.bounds();
+        first.set_geometry(geometry.clone());
+
+        let second = DrawingObject::new(geometry, layer);
+
+        assert_eq!(first, second);
+        assert_eq!(first.clone().bounds(), second.bounds());
+    }
+
+    #[test]
+    fn an_empty_drawing_has_no_bounds() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+
+        assert_eq!(drawing_bounds(&world), None);
+    }
+
+    #[test]
+    fn drawing_bounds_prefer_the_cached_bounding_box() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = world.create_entity().build();
+        world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(1.0, 2.0)),
+                layer,
+            ))
+            .build();
+        // a stale cache entry, so we can tell it was used
+        world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(3.0, 4.0)),
+                layer,
+            ))
+            .with(BoundingBox::new(
+                Point::new(-5.0, -5.0),
+                Point::new(-4.0, -4.0),
+            ))
+            .build();
+
+        let got = drawing_bounds(&world).unwrap();
+
+        assert_eq!(
+            got,
+            BoundingBox::new(Point::new(-5.0, -5.0), Point::new(1.0, 2.0))
+        );
     }
 }
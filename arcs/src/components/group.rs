@@ -0,0 +1,157 @@
+use crate::components::Selected;
+use specs::prelude::*;
+use specs_derive::Component;
+use std::collections::HashSet;
+
+/// Marks an [`Entity`] as a member of a group which should be selected and
+/// manipulated as a single unit.
+///
+/// Groups are hierarchical. The `parent` may itself be part of another
+/// [`Group`], in which case selecting any member will select everything under
+/// the outermost group.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Component)]
+#[storage(DenseVecStorage)]
+pub struct Group {
+    /// The [`Entity`] representing the group as a whole.
+    pub parent: Entity,
+}
+
+impl Group {
+    /// Find the outermost group an [`Entity`] belongs to, or the [`Entity`]
+    /// itself if it isn't part of a [`Group`].
+    pub fn root(entity: Entity, groups: &ReadStorage<'_, Group>) -> Entity {
+        ancestors(entity, groups, groups.count())
+            .last()
+            .unwrap_or(entity)
+    }
+
+    /// Iterate over every [`Entity`] which is (directly or indirectly) a
+    /// member of the group represented by `parent`.
+    pub fn members<'a>(
+        parent: Entity,
+        entities: &'a Entities<'a>,
+        groups: &'a ReadStorage<'a, Group>,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        let max_depth = groups.count();
+
+        (entities, groups)
+            .join()
+            .map(|(ent, _)| ent)
+            .filter(move |&ent| {
+                ancestors(ent, groups, max_depth)
+                    .any(|ancestor| ancestor == parent)
+            })
+    }
+
+    /// Make sure that if any member of a [`Group`] is [`Selected`], all
+    /// other members are selected too.
+    ///
+    /// Operations on the selection (e.g. translating or deleting) should call
+    /// this first so they treat groups as a single unit.
+    pub fn select_whole_groups(
+        entities: &Entities<'_>,
+        groups: &ReadStorage<'_, Group>,
+        selected: &mut WriteStorage<'_, Selected>,
+    ) {
+        let max_depth = groups.count();
+        let root =
+            |ent| ancestors(ent, groups, max_depth).last().unwrap_or(ent);
+
+        let selected_roots: HashSet<Entity> = (entities, &*selected)
+            .join()
+            .map(|(ent, _)| root(ent))
+            .collect();
+
+        let to_select: Vec<Entity> = (entities, groups)
+            .join()
+            .map(|(ent, _)| ent)
+            .filter(|&ent| selected_roots.contains(&root(ent)))
+            .collect();
+
+        for ent in to_select {
+            selected.insert(ent, Selected).expect("The entity is alive");
+        }
+    }
+}
+
+/// Walk up the [`Group`] hierarchy, yielding each parent in turn.
+///
+/// The walk is limited to `max_depth` steps so a cycle can't hang us.
+fn ancestors<'a>(
+    entity: Entity,
+    groups: &'a ReadStorage<'a, Group>,
+    max_depth: usize,
+) -> impl Iterator<Item = Entity> + 'a {
+    std::iter::successors(groups.get(entity).map(|g| g.parent), move |&ent| {
+        groups.get(ent).map(|g| g.parent)
+    })
+    .take(max_depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selecting_one_member_selects_the_whole_group() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let parent = world.create_entity().build();
+        let first = world.create_entity().with(Group { parent }).build();
+        let second = world.create_entity().with(Group { parent }).build();
+        let unrelated = world.create_entity().build();
+        world.write_storage().insert(second, Selected).unwrap();
+
+        Group::select_whole_groups(
+            &world.entities(),
+            &world.read_storage(),
+            &mut world.write_storage(),
+        );
+
+        let selected = world.read_storage::<Selected>();
+        assert!(selected.contains(first));
+        assert!(selected.contains(second));
+        assert!(!selected.contains(unrelated));
+    }
+
+    #[test]
+    fn nested_groups_are_selected_from_the_outermost_group() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let outer = world.create_entity().build();
+        let inner = world.create_entity().with(Group { parent: outer }).build();
+        let first = world.create_entity().with(Group { parent: inner }).build();
+        let second =
+            world.create_entity().with(Group { parent: outer }).build();
+        world.write_storage().insert(first, Selected).unwrap();
+
+        Group::select_whole_groups(
+            &world.entities(),
+            &world.read_storage(),
+            &mut world.write_storage(),
+        );
+
+        let selected = world.read_storage::<Selected>();
+        assert!(selected.contains(inner));
+        assert!(selected.contains(first));
+        assert!(selected.contains(second));
+    }
+
+    #[test]
+    fn find_all_members_of_a_group() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let outer = world.create_entity().build();
+        let inner = world.create_entity().with(Group { parent: outer }).build();
+        let first = world.create_entity().with(Group { parent: inner }).build();
+        let _unrelated = world.create_entity().build();
+
+        let entities = world.entities();
+        let groups = world.read_storage();
+        let got: HashSet<_> =
+            Group::members(outer, &entities, &groups).collect();
+
+        let expected: HashSet<_> = vec![inner, first].into_iter().collect();
+        assert_eq!(got, expected);
+    }
+}
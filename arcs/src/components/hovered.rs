@@ -0,0 +1,146 @@
+use crate::{components::DrawingObject, window::DirtyRegions};
+use specs::prelude::*;
+use specs_derive::Component;
+
+/// An empty [`Component`] used to mark the [`Entity`] under the cursor, so it
+/// can be highlighted before the user clicks on it.
+///
+/// Hovered objects are drawn using
+/// [`crate::components::WindowStyle::hover_colour`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Component)]
+#[storage(NullStorage)]
+pub struct Hovered;
+
+impl Hovered {
+    /// Make `entity` the only [`Hovered`] entity, or clear the hover when
+    /// `entity` is `None`.
+    ///
+    /// This returns `true` if anything changed, so a mouse move handler only
+    /// needs to request a redraw when the hovered object is different. The
+    /// old and new objects are also marked in the [`DirtyRegions`] resource
+    /// (if present) so incremental renderers know to repaint them.
+    ///
+    /// Hit tests may still return entities which have just been deleted (e.g.
+    /// because the [`crate::components::Space`] hasn't been updated yet), so
+    /// a dead `entity` is treated like `None`.
+    pub fn set(world: &World, entity: Option<Entity>) -> bool {
+        let entities = world.entities();
+        let mut hovered = world.write_storage::<Hovered>();
+        let entity = entity.filter(|&ent| entities.is_alive(ent));
+
+        let previous: Vec<Entity> = (&entities, &hovered)
+            .join()
+            .map(|(ent, _)| ent)
+            .filter(|&ent| Some(ent) != entity)
+            .collect();
+        let already_hovered = entity.is_some_and(|ent| hovered.contains(ent));
+
+        if previous.is_empty() && (entity.is_none() || already_hovered) {
+            return false;
+        }
+
+        for &ent in &previous {
+            hovered.remove(ent);
+        }
+        if let Some(ent) = entity {
+            hovered
+                .insert(ent, Hovered)
+                .expect("We just checked the entity is alive");
+        }
+
+        if let Some(mut dirty) = world.try_fetch_mut::<DirtyRegions>() {
+            let drawing_objects = world.read_storage::<DrawingObject>();
+
+            for ent in previous.into_iter().chain(entity) {
+                if let Some(obj) = drawing_objects.get(ent) {
+                    dirty.mark(obj.bounds());
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::Geometry, Point};
+
+    fn hovered(world: &World) -> Vec<Entity> {
+        (&world.entities(), &world.read_storage::<Hovered>())
+            .join()
+            .map(|(ent, _)| ent)
+            .collect()
+    }
+
+    #[test]
+    fn only_one_entity_is_hovered_at_a_time() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        world.insert(DirtyRegions::default());
+        let layer = world.create_entity().build();
+        let first = world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(1.0, 1.0)),
+                layer,
+            ))
+            .build();
+        let second = world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(5.0, 5.0)),
+                layer,
+            ))
+            .build();
+
+        assert!(Hovered::set(&world, Some(first)));
+        assert_eq!(hovered(&world), vec![first]);
+
+        // moving the mouse within the same object doesn't change anything
+        world.write_resource::<DirtyRegions>().clear();
+        assert!(!Hovered::set(&world, Some(first)));
+        assert!(world.read_resource::<DirtyRegions>().is_empty());
+
+        assert!(Hovered::set(&world, Some(second)));
+        assert_eq!(hovered(&world), vec![second]);
+        // both the old and new objects need to be redrawn
+        assert_eq!(
+            world.read_resource::<DirtyRegions>().bounds(),
+            Some(crate::BoundingBox::new(
+                Point::new(1.0, 1.0),
+                Point::new(5.0, 5.0)
+            ))
+        );
+    }
+
+    #[test]
+    fn clear_the_hover() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let ent = world.create_entity().build();
+
+        assert!(!Hovered::set(&world, None));
+        assert!(Hovered::set(&world, Some(ent)));
+        assert!(Hovered::set(&world, None));
+
+        assert!(hovered(&world).is_empty());
+    }
+
+    #[test]
+    fn dead_entities_cant_be_hovered() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let alive = world.create_entity().build();
+        let dead = world.create_entity().build();
+        world.delete_entity(dead).unwrap();
+
+        assert!(!Hovered::set(&world, Some(dead)));
+
+        // hovering over a dead entity clears the old hover
+        assert!(Hovered::set(&world, Some(alive)));
+        assert!(Hovered::set(&world, Some(dead)));
+        assert!(hovered(&world).is_empty());
+    }
+}
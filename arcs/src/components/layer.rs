@@ -1,4 +1,8 @@
-use crate::components::Name;
+use crate::{
+    components::{DrawingObject, LineStyle, Name, PointStyle},
+    window::DirtyRegions,
+    BoundingBox,
+};
 use specs::prelude::*;
 use specs_derive::Component;
 
@@ -17,6 +21,125 @@ impl Layer {
     pub fn create(builder: EntityBuilder, name: Name, layer: Layer) -> Entity {
         builder.with(layer).with(name).build()
     }
+
+    /// Create a [`Layer`] with its own [`LineStyle`] and [`PointStyle`].
+    ///
+    /// Objects on this [`Layer`] will use these styles unless they've been
+    /// given their own.
+    pub fn create_with_styles(
+        builder: EntityBuilder,
+        name: Name,
+        layer: Layer,
+        line_style: LineStyle,
+        point_style: PointStyle,
+    ) -> Entity {
+        builder
+            .with(layer)
+            .with(name)
+            .with(line_style)
+            .with(point_style)
+            .build()
+    }
+
+    /// Show or hide a [`Layer`], returning its new visibility.
+    ///
+    /// The area covered by the layer's [`DrawingObject`]s is marked in the
+    /// [`DirtyRegions`] resource (if present) so incremental renderers know
+    /// to repaint it.
+    pub fn toggle_visibility(world: &World, layer: Entity) -> bool {
+        let visible = {
+            let mut layers = world.write_storage::<Layer>();
+            let layer = layers.get_mut(layer).expect("Not a layer");
+            layer.visible = !layer.visible;
+            layer.visible
+        };
+
+        if let Some(mut dirty) = world.try_fetch_mut::<DirtyRegions>() {
+            let drawing_objects = world.read_storage::<DrawingObject>();
+            let bounds = BoundingBox::around(
+                drawing_objects
+                    .join()
+                    .filter(|obj| obj.layer == layer)
+                    .map(|obj| obj.bounds()),
+            );
+
+            if let Some(bounds) = bounds {
+                dirty.mark(bounds);
+            }
+        }
+
+        visible
+    }
+
+    /// Renumber the z-levels so `layer` is drawn just above `other`.
+    ///
+    /// All z-levels are compacted to `0..n` in the process (see
+    /// [`Layer::normalise_z_levels()`]).
+    pub fn move_above(world: &World, layer: Entity, other: Entity) {
+        Layer::reorder(world, layer, other, 0);
+    }
+
+    /// Renumber the z-levels so `layer` is drawn just below `other`.
+    ///
+    /// All z-levels are compacted to `0..n` in the process (see
+    /// [`Layer::normalise_z_levels()`]).
+    pub fn move_below(world: &World, layer: Entity, other: Entity) {
+        Layer::reorder(world, layer, other, 1);
+    }
+
+    /// Compact the z-levels of every [`Layer`] to `0..n`, keeping their
+    /// relative order.
+    ///
+    /// Layers which share a z-level are ordered by [`Entity`] so the result is
+    /// deterministic.
+    pub fn normalise_z_levels(world: &World) {
+        let mut layers = world.write_storage::<Layer>();
+        let order = Layer::draw_order(&world.entities(), &layers);
+
+        Layer::renumber(&mut layers, &order);
+    }
+
+    /// Move `layer` next to `other`, where an `offset` of `0` places it above
+    /// and `1` places it below.
+    fn reorder(world: &World, layer: Entity, other: Entity, offset: usize) {
+        if layer == other {
+            return;
+        }
+
+        let mut layers = world.write_storage::<Layer>();
+        let mut order = Layer::draw_order(&world.entities(), &layers);
+        order.retain(|&ent| ent != layer);
+
+        let position = order
+            .iter()
+            .position(|&ent| ent == other)
+            .expect("The other entity isn't a layer");
+        order.insert(position + offset, layer);
+
+        Layer::renumber(&mut layers, &order);
+    }
+
+    /// All layers, from the top-most (lowest z-level) down.
+    fn draw_order(
+        entities: &Entities<'_>,
+        layers: &WriteStorage<'_, Layer>,
+    ) -> Vec<Entity> {
+        let mut order: Vec<_> = (entities, layers)
+            .join()
+            .map(|(ent, layer)| (layer.z_level, ent))
+            .collect();
+        order.sort();
+
+        order.into_iter().map(|(_, ent)| ent).collect()
+    }
+
+    fn renumber(layers: &mut WriteStorage<'_, Layer>, order: &[Entity]) {
+        for (z_level, &ent) in order.iter().enumerate() {
+            if let Some(layer) = layers.get_mut(ent) {
+                layer.z_level = z_level;
+            }
+        }
+    }
 }
 
 impl Default for Layer {
@@ -27,3 +150,115 @@ impl Default for Layer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::Geometry, Line, Point};
+
+    fn layer_at(world: &mut World, name: &str, z_level: usize) -> Entity {
+        Layer::create(
+            world.create_entity(),
+            Name::new(name),
+            Layer {
+                z_level,
+                visible: true,
+            },
+        )
+    }
+
+    fn z_level(world: &World, layer: Entity) -> usize {
+        world.read_storage::<Layer>().get(layer).unwrap().z_level
+    }
+
+    #[test]
+    fn create_a_layer_with_styles() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+
+        let layer = Layer::create_with_styles(
+            world.create_entity(),
+            Name::new("styled"),
+            Layer::default(),
+            LineStyle::default(),
+            PointStyle::default(),
+        );
+
+        assert!(world.read_storage::<LineStyle>().contains(layer));
+        assert!(world.read_storage::<PointStyle>().contains(layer));
+    }
+
+    #[test]
+    fn toggling_visibility_marks_the_layer_dirty() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        world.insert(DirtyRegions::default());
+        let layer = layer_at(&mut world, "layer", 0);
+        let _other = layer_at(&mut world, "other", 1);
+        let _ = world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Line(Line::new(
+                    Point::new(0.0, 0.0),
+                    Point::new(5.0, 5.0),
+                )),
+                layer,
+            ))
+            .build();
+
+        assert!(!Layer::toggle_visibility(&world, layer));
+        assert!(!world.read_storage::<Layer>().get(layer).unwrap().visible);
+        assert_eq!(
+            world.fetch::<DirtyRegions>().bounds(),
+            Some(BoundingBox::new(Point::new(0.0, 0.0), Point::new(5.0, 5.0)))
+        );
+
+        assert!(Layer::toggle_visibility(&world, layer));
+    }
+
+    #[test]
+    fn reordering_two_layers_flips_their_draw_order() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let top = layer_at(&mut world, "top", 0);
+        let bottom = layer_at(&mut world, "bottom", 5);
+
+        Layer::move_above(&world, bottom, top);
+
+        assert!(z_level(&world, bottom) < z_level(&world, top));
+
+        Layer::move_below(&world, bottom, top);
+
+        assert!(z_level(&world, bottom) > z_level(&world, top));
+    }
+
+    #[test]
+    fn move_a_layer_between_two_others() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let first = layer_at(&mut world, "first", 0);
+        let second = layer_at(&mut world, "second", 1);
+        let third = layer_at(&mut world, "third", 2);
+
+        Layer::move_below(&world, third, first);
+
+        assert_eq!(z_level(&world, first), 0);
+        assert_eq!(z_level(&world, third), 1);
+        assert_eq!(z_level(&world, second), 2);
+    }
+
+    #[test]
+    fn normalise_sparse_z_levels() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let first = layer_at(&mut world, "first", 3);
+        let second = layer_at(&mut world, "second", 10);
+        let third = layer_at(&mut world, "third", 42);
+
+        Layer::normalise_z_levels(&world);
+
+        assert_eq!(z_level(&world, first), 0);
+        assert_eq!(z_level(&world, second), 1);
+        assert_eq!(z_level(&world, third), 2);
+    }
+}
@@ -1,7 +1,11 @@
 //! Common components used by the `arcs` CAD library.
 
+mod construction;
 mod dimension;
+mod draw_priority;
 mod drawing_object;
+mod group;
+mod hovered;
 mod layer;
 mod name;
 mod selected;
@@ -9,21 +13,30 @@ mod styles;
 mod viewport;
 mod vtable;
 
-// FIXME: I'm not 100% sure this was the right approach for a quadtree...
-// mod spatial_entity;
-// pub use spatial_entity::{Space, SpatialEntity};
+mod spatial_entity;
 
-pub use dimension::Dimension;
-pub use drawing_object::{DrawingObject, Geometry};
+pub use construction::Construction;
+pub use dimension::{Dimension, DrawingUnit};
+pub use draw_priority::DrawPriority;
+pub use drawing_object::{
+    drawing_bounds, DrawingObject, Geometry, GeometryKind,
+};
+pub use group::Group;
+pub use hovered::Hovered;
 pub use layer::Layer;
-pub use name::{Name, NameTable};
-pub use selected::Selected;
-pub use styles::{LineStyle, PointStyle, WindowStyle};
+pub use name::{entities_matching, find_by_name, Name, NameTable};
+pub use selected::{selected_geometry, Selected};
+pub use spatial_entity::{Space, SpatialEntity};
+pub use styles::{
+    colour_from_hex, colour_to_hex, LineStyle, ParseColourError, PointStyle,
+    WindowStyle,
+};
 pub use viewport::Viewport;
-pub(crate) use vtable::ComponentVtable;
+pub use vtable::ComponentVtable;
 
-use specs::World;
 use crate::DrawingSpace;
+use specs::{Entity, World};
+use std::any::Any;
 
 /// Get an iterator over the [`ComponentVtable`] for all known
 /// [`specs::Component`] types.
@@ -32,7 +45,11 @@ pub(crate) fn known_components(
     lazy_static::lazy_static! {
         static ref VTABLES: Vec<ComponentVtable> = vec![
             ComponentVtable::for_type::<arcs_core::BoundingBox<DrawingSpace>>(),
+            ComponentVtable::for_type::<Construction>(),
             ComponentVtable::for_type::<DrawingObject>(),
+            ComponentVtable::for_type::<DrawPriority>(),
+            ComponentVtable::for_type::<Group>(),
+            ComponentVtable::for_type::<Hovered>(),
             ComponentVtable::for_type::<Layer>(),
             ComponentVtable::for_type::<Name>(),
             ComponentVtable::for_type::<LineStyle>(),
@@ -55,3 +72,63 @@ pub fn register(world: &mut World) {
         component.register(world);
     }
 }
+
+/// Get a copy of every known [`specs::Component`] attached to `entity`, along
+/// with the [`ComponentVtable`] for its type.
+///
+/// This is useful for things like undoing a deletion, where we need to put
+/// back an entity's components without knowing their types up front.
+pub fn snapshot_entity(
+    world: &World,
+    entity: Entity,
+) -> Vec<(&'static ComponentVtable, Box<dyn Any>)> {
+    known_components()
+        .filter_map(|vtable| {
+            vtable
+                .snapshot(world, entity)
+                .map(|component| (vtable, component))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specs::{Builder, WorldExt};
+
+    #[test]
+    fn snapshot_every_component_on_an_entity() {
+        let mut world = World::new();
+        register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("layer"),
+            Layer::default(),
+        );
+
+        let got = snapshot_entity(&world, layer);
+
+        let names: Vec<_> =
+            got.iter().map(|(vtable, _)| vtable.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                std::any::type_name::<Layer>(),
+                std::any::type_name::<Name>()
+            ]
+        );
+        assert_eq!(got[0].1.downcast_ref::<Layer>(), Some(&Layer::default()));
+        assert_eq!(got[1].1.downcast_ref::<Name>(), Some(&Name::new("layer")));
+    }
+
+    #[test]
+    fn dead_entities_have_no_components() {
+        let mut world = World::new();
+        register(&mut world);
+        let ent = world.create_entity().with(Selected).build();
+        world.delete_entity(ent).unwrap();
+        world.maintain();
+
+        assert!(snapshot_entity(&world, ent).is_empty());
+    }
+}
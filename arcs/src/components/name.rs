@@ -51,7 +51,7 @@ impl NameTable {
 
     pub fn iter<'this>(
         &'this self,
-    ) -> impl Iterator<Item = (&str, Entity)> + 'this {
+    ) -> impl Iterator<Item = (&'this str, Entity)> + 'this {
         self.names.iter().map(|(name, ent)| (name.as_ref(), *ent))
     }
 
@@ -61,6 +61,34 @@ impl NameTable {
 
     pub fn is_empty(&self) -> bool { self.names.is_empty() }
 
+    /// Get a version of `name` which isn't already taken, adding a numeric
+    /// suffix if necessary (e.g. `foo` becomes `foo_1`).
+    pub fn unique_name(&self, name: &str) -> Name {
+        if !self.names.contains_key(name) {
+            return Name::new(name);
+        }
+
+        (1..)
+            .map(|i| format!("{}_{}", name, i))
+            .find(|candidate| !self.names.contains_key(candidate))
+            .map(Name::new)
+            .expect("There are always more suffixes to try")
+    }
+
+    /// Associate an [`Entity`] with a [`Name`] without overwriting any
+    /// existing entries, returning the [`Name`] that was actually used.
+    ///
+    /// If the [`Name`] is already taken it will be given a suffix (see
+    /// [`NameTable::unique_name()`]). Make sure the returned [`Name`] is the
+    /// one attached to the [`Entity`] so the [`NameTable`] bookkeeping stays
+    /// consistent.
+    pub fn insert_unique(&mut self, name: &str, ent: Entity) -> Name {
+        let name = self.unique_name(name);
+        self.names.insert(name.clone(), ent);
+
+        name
+    }
+
     pub fn remove_by_id(&mut self, id: Index) {
         let filter = move |(name, ent): (&Name, &Entity)| {
             if ent.id() == id {
@@ -76,3 +104,89 @@ impl NameTable {
         }
     }
 }
+
+/// Look up an [`Entity`] by its [`Name`].
+///
+/// This reads the [`NameTable`], so it will return `None` if the
+/// [`crate::systems::NameTableBookkeeping`] system hasn't run yet.
+pub fn find_by_name(world: &World, name: &str) -> Option<Entity> {
+    world.try_fetch::<NameTable>()?.get(name)
+}
+
+/// Find all entities whose [`Name`] starts with a particular prefix, sorted by
+/// name.
+///
+/// Like [`find_by_name()`], this will be empty if the [`NameTable`] hasn't
+/// been populated yet.
+pub fn entities_matching(world: &World, prefix: &str) -> Vec<Entity> {
+    let name_table = match world.try_fetch::<NameTable>() {
+        Some(name_table) => name_table,
+        None => return Vec::new(),
+    };
+
+    let mut matches: Vec<_> = name_table
+        .iter()
+        .filter(|(name, _)| name.starts_with(prefix))
+        .collect();
+    matches.sort_by_key(|&(name, _)| name);
+
+    matches.into_iter().map(|(_, ent)| ent).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_names_get_a_suffix() {
+        let mut world = World::new();
+        let first = world.create_entity().build();
+        let second = world.create_entity().build();
+        let third = world.create_entity().build();
+        let mut names = NameTable::default();
+
+        assert_eq!(names.insert_unique("foo", first), Name::new("foo"));
+        assert_eq!(names.insert_unique("foo", second), Name::new("foo_1"));
+        assert_eq!(names.insert_unique("foo", third), Name::new("foo_2"));
+
+        assert_eq!(names.get("foo"), Some(first));
+        assert_eq!(names.get("foo_1"), Some(second));
+        assert_eq!(names.get("foo_2"), Some(third));
+    }
+
+    #[test]
+    fn unused_names_are_left_alone() {
+        let names = NameTable::default();
+
+        assert_eq!(names.unique_name("bar"), Name::new("bar"));
+    }
+
+    #[test]
+    fn look_up_entities_by_name() {
+        let mut world = World::new();
+        let default = world.create_entity().build();
+        let dimensions = world.create_entity().build();
+        let details = world.create_entity().build();
+        let mut names = NameTable::default();
+        names.insert_unique("default", default);
+        names.insert_unique("dimensions", dimensions);
+        names.insert_unique("details", details);
+        world.insert(names);
+
+        assert_eq!(find_by_name(&world, "default"), Some(default));
+        assert_eq!(find_by_name(&world, "missing"), None);
+        assert_eq!(
+            entities_matching(&world, "d"),
+            vec![default, details, dimensions]
+        );
+        assert_eq!(entities_matching(&world, "di"), vec![dimensions]);
+    }
+
+    #[test]
+    fn nothing_is_found_without_a_name_table() {
+        let world = World::new();
+
+        assert_eq!(find_by_name(&world, "default"), None);
+        assert!(entities_matching(&world, "").is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::components::{DrawingObject, Geometry};
 use specs::prelude::*;
 use specs_derive::Component;
 
@@ -5,3 +6,49 @@ use specs_derive::Component;
 #[derive(Debug, Copy, Clone, Default, PartialEq, Component)]
 #[storage(NullStorage)]
 pub struct Selected;
+
+/// Get a copy of the [`Geometry`] for every [`Selected`] [`DrawingObject`].
+pub fn selected_geometry(world: &World) -> Vec<(Entity, Geometry)> {
+    let entities = world.entities();
+    let selected = world.read_storage::<Selected>();
+    let drawing_objects = world.read_storage::<DrawingObject>();
+
+    (&entities, &selected, &drawing_objects)
+        .join()
+        .map(|(ent, _, obj)| (ent, obj.geometry().clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    #[test]
+    fn only_get_selected_geometry() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = world.create_entity().build();
+        let selected = world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(1.0, 2.0)),
+                layer,
+            ))
+            .with(Selected)
+            .build();
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .build();
+        // selected, but not a drawing object
+        world.create_entity().with(Selected).build();
+
+        let got = selected_geometry(&world);
+
+        assert_eq!(
+            got,
+            vec![(selected, Geometry::Point(Point::new(1.0, 2.0)))]
+        );
+    }
+}
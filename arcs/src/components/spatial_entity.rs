@@ -1,44 +1,28 @@
-use crate::{algorithms::Bounded, Arc, Point};
-use aabb_quadtree::{ItemId, QuadTree, Spatial};
-use arcs_core::BoundingBox;
-use euclid::Angle;
-use quadtree_euclid::{TypedPoint2D, TypedRect, TypedSize2D};
-use specs::{world::Index, Entity};
-use std::collections::HashMap;
+use crate::{
+    algorithms::ClosestPoint, components::DrawingObject, BoundingBox,
+    DrawingSpace, Length, Point,
+};
+use specs::{world::Index, Entity, ReadStorage};
+use std::collections::{HashMap, HashSet};
 
 #[allow(unused_imports)] // for rustdoc links
 use specs::prelude::Resource;
 
-pub(crate) type SpatialTree =
-    QuadTree<SpatialEntity, f64, [(ItemId, TypedRect<f32, f64>); 0]>;
-
 /// A intermediate struct that maps an [`Entity`] to its [`BoundingBox`]
 ///
 /// This is used to populate an efficient spatial lookup structure like a
 /// `QuadTree`
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct SpatialEntity {
-    pub bounds: BoundingBox,
+    pub bounds: BoundingBox<DrawingSpace>,
     pub entity: Entity,
 }
 
-impl Spatial<f64> for SpatialEntity {
-    fn aabb(&self) -> TypedRect<f32, f64> {
-        let bb = self.bounds;
-        TypedRect::<f32, f64>::new(
-            // TypedRects have their origin at the bottom left corner (this is
-            // undocumented!)
-            TypedPoint2D::new(
-                bb.bottom_left().x as f32,
-                bb.bottom_left().y as f32,
-            ),
-            TypedSize2D::new(bb.width().0 as f32, bb.height().0 as f32),
-        )
-    }
-}
-
 impl SpatialEntity {
-    pub fn new(bounds: BoundingBox, entity: Entity) -> SpatialEntity {
+    pub fn new(
+        bounds: BoundingBox<DrawingSpace>,
+        entity: Entity,
+    ) -> SpatialEntity {
         SpatialEntity { bounds, entity }
     }
 }
@@ -47,119 +31,67 @@ impl SpatialEntity {
 /// a given spatial point or region
 #[derive(Debug)]
 pub struct Space {
-    quadtree: SpatialTree,
-    ids: HashMap<Entity, ItemId>,
+    quadtree: Node,
+    bounds: HashMap<Entity, BoundingBox<DrawingSpace>>,
 }
 
 impl Default for Space {
     fn default() -> Self {
         Space {
-            quadtree: Self::default_tree(),
-            ids: HashMap::new(),
+            quadtree: Node::new(Self::default_world_size(), 0),
+            bounds: HashMap::new(),
         }
     }
 }
 
 impl Space {
-    const TREE_ALLOW_DUPLICATES: bool = true;
     const TREE_MAX_CHILDREN: usize = 16;
     const TREE_MAX_DEPTH: usize = 8;
-    const TREE_MIN_CHILDREN: usize = 4;
-    const TREE_SIZE_HINT: usize = 4;
     // FIXME: Hard-code is bad-bad
     pub const WORLD_RADIUS: f64 = 1_000_000.0;
 
-    fn default_tree() -> SpatialTree {
-        // Initialize quadtree
-        let size = BoundingBox::new(
+    fn default_world_size() -> BoundingBox<DrawingSpace> {
+        BoundingBox::new(
             Point::new(-Self::WORLD_RADIUS, -Self::WORLD_RADIUS),
             Point::new(Self::WORLD_RADIUS, Self::WORLD_RADIUS),
         )
-        .aabb();
-        let quadtree: SpatialTree = QuadTree::new(
-            size,
-            Self::TREE_ALLOW_DUPLICATES,
-            Self::TREE_MIN_CHILDREN,
-            Self::TREE_MAX_CHILDREN,
-            Self::TREE_MAX_DEPTH,
-            Self::TREE_SIZE_HINT,
-        );
-
-        quadtree
-    }
-
-    fn tree_with_world_size(size: impl Spatial<f64>) -> SpatialTree {
-        let quadtree: SpatialTree = QuadTree::new(
-            size.aabb(),
-            Self::TREE_ALLOW_DUPLICATES,
-            Self::TREE_MIN_CHILDREN,
-            Self::TREE_MAX_CHILDREN,
-            Self::TREE_MAX_DEPTH,
-            Self::TREE_SIZE_HINT,
-        );
-
-        quadtree
     }
 
     /// Modifies the spatial position of the given [`SpatialEntity`] inside of
     /// [`Space`] If the [`SpatialEntity`] is not already inside of
     /// [`Space`] it will be inserted.
+    ///
+    /// Bounds containing a `NaN` or infinite coordinate can't be placed in the
+    /// quadtree, so the [`Entity`] is removed instead.
     pub fn modify(&mut self, spatial: SpatialEntity) {
-        if !self
-            .quadtree
-            .bounding_box()
-            .contains_rect(&spatial.bounds.aabb())
-        {
-            self.resize(spatial.bounds);
+        if !is_finite(spatial.bounds) {
+            self.remove(spatial.entity);
+            return;
         }
-        let id = if self.ids.contains_key(&spatial.entity) {
-            self.modify_entity(spatial)
-        } else {
-            self.insert_entity(spatial)
-        };
-        // Update hashmap
-        self.ids.entry(spatial.entity).or_insert(id);
-    }
 
-    fn insert_entity(&mut self, spatial: SpatialEntity) -> ItemId {
-        if let Some(id) = self.quadtree.insert(spatial) {
-            id
-        } else {
-            panic!("ERROR: Failed to insert {:?} into Space!", self)
-        }
-    }
+        let world_size = self.quadtree.bounds;
 
-    fn modify_entity(&mut self, spatial: SpatialEntity) -> ItemId {
-        let item_id = self.ids[&spatial.entity];
-        // remove old item
-        self.quadtree.remove(item_id);
+        if !world_size.fully_contains(spatial.bounds) {
+            self.resize(BoundingBox::merge(world_size, spatial.bounds));
+        }
 
-        // Add modified
-        self.insert_entity(spatial)
+        self.remove(spatial.entity);
+        self.quadtree.insert(spatial);
+        self.bounds.insert(spatial.entity, spatial.bounds);
     }
 
     /// Removes the given [`Entity`] from this [`Space`]
     pub fn remove(&mut self, entity: Entity) {
-        if self.ids.contains_key(&entity) {
-            let item_id = self.ids[&entity];
-
-            // remove old item
-            self.quadtree.remove(item_id);
-            self.ids.remove(&entity);
+        if let Some(bounds) = self.bounds.remove(&entity) {
+            self.quadtree.remove(entity, bounds);
         }
     }
 
     /// Removes an [`Entity`] from this [`Space`] given its [`Index`]
     pub fn remove_by_id(&mut self, id: Index) {
-        let filter = move |(ent, _item_id): (&Entity, &ItemId)| {
-            if ent.id() == id {
-                Some(*ent)
-            } else {
-                None
-            }
-        };
+        let ent = self.bounds.keys().copied().find(|ent| ent.id() == id);
 
-        if let Some(ent) = self.ids.iter().filter_map(filter).next() {
+        if let Some(ent) = ent {
             self.remove(ent);
         }
     }
@@ -168,49 +100,150 @@ impl Space {
     pub fn iter<'this>(
         &'this self,
     ) -> impl Iterator<Item = SpatialEntity> + 'this {
-        self.quadtree.iter().map(|(_, (ent, _))| *ent)
+        self.bounds
+            .iter()
+            .map(|(&entity, &bounds)| SpatialEntity::new(bounds, entity))
     }
 
-    pub fn len(&self) -> usize { self.quadtree.len() }
+    pub fn len(&self) -> usize { self.bounds.len() }
 
-    pub fn is_empty(&self) -> bool { self.quadtree.is_empty() }
+    pub fn is_empty(&self) -> bool { self.bounds.is_empty() }
+
+    /// The [`BoundingBox`] around everything in this [`Space`], or `None`
+    /// if it is empty.
+    ///
+    /// Unlike [`crate::drawing_bounds()`] this only uses the bounds stored in
+    /// the index, so it doesn't need access to the [`specs::World`].
+    pub fn total_bounds(&self) -> Option<BoundingBox<DrawingSpace>> {
+        BoundingBox::around(self.bounds.values())
+    }
 
-    // FIXME: radius in CanvasSpace in method signature
     /// Performs a spatial query in an radius around a given [`Point`]
     /// Returns an iterator with all [`SpatialEntity`] inhabiting the [`Space`]
     /// close to the given point
     /// The returned iterator can be empty
-    pub fn query_point<'this>(
-        &'this self,
+    ///
+    /// The radius is in drawing units, so a pick radius measured in pixels
+    /// needs to be divided by the [`crate::components::Viewport`]'s
+    /// `pixels_per_drawing_unit` first.
+    pub fn query_point(
+        &self,
         point: Point,
-        radius: f64,
-    ) -> impl Iterator<Item = SpatialEntity> + 'this {
-        let cursor_circle = Arc::from_centre_radius(
-            point,
-            radius,
-            Angle::radians(0.0),
-            Angle::radians(2.0 * std::f64::consts::PI),
-        );
-        self.query_region(cursor_circle.bounding_box())
+        radius: Length,
+    ) -> impl Iterator<Item = SpatialEntity> {
+        self.query_region(BoundingBox::new(point, point).expanded(radius))
+    }
+
+    /// Like [`Space::query_point()`], except the results are sorted so the
+    /// [`SpatialEntity`] whose geometry is actually closest to `point` comes
+    /// first.
+    ///
+    /// Entities without a [`DrawingObject`] are put at the end.
+    pub fn query_point_sorted(
+        &self,
+        point: Point,
+        radius: Length,
+        drawing_objects: &ReadStorage<'_, DrawingObject>,
+    ) -> Vec<SpatialEntity> {
+        let distance_to = |spatial: &SpatialEntity| {
+            drawing_objects
+                .get(spatial.entity)
+                .map(|obj| obj.closest_point(point))
+                .and_then(|closest| closest.distance_to(point))
+                .unwrap_or(f64::INFINITY)
+        };
+
+        let mut found: Vec<_> = self
+            .query_point(point, radius)
+            .map(|spatial| (distance_to(&spatial), spatial))
+            .collect();
+        found.sort_by(|(left, _), (right, _)| left.total_cmp(right));
+
+        found.into_iter().map(|(_, spatial)| spatial).collect()
+    }
+
+    /// Find groups of entities whose [`BoundingBox`]es are the same, to
+    /// within `tolerance`.
+    ///
+    /// Two bounding boxes coincide when both of their corners are within
+    /// `tolerance` of each other. This is handy for finding duplicate geometry
+    /// (e.g. the same vertex imported on many segments) so it can be merged.
+    ///
+    /// Each group contains at least two entities, sorted so the result is
+    /// deterministic. Grouping isn't transitive. Each group is built around
+    /// its first (lowest) entity, and an entity only joins a group if it
+    /// coincides with that first entity.
+    pub fn find_coincident(&self, tolerance: Length) -> Vec<Vec<Entity>> {
+        let coincides = |a: BoundingBox<DrawingSpace>,
+                         b: BoundingBox<DrawingSpace>| {
+            a.bottom_left().distance_to(b.bottom_left()) <= tolerance.get()
+                && a.top_right().distance_to(b.top_right()) <= tolerance.get()
+        };
+
+        let mut entities: Vec<_> = self.bounds.keys().copied().collect();
+        entities.sort();
+        let mut grouped = HashSet::new();
+        let mut groups = Vec::new();
+
+        for ent in entities {
+            if grouped.contains(&ent) {
+                continue;
+            }
+
+            let bounds = self.bounds[&ent];
+            let centre = bounds.bottom_left() + bounds.diagonal() / 2.0;
+            let mut group: Vec<_> = self
+                .query_point(centre, tolerance)
+                .filter(|spatial| {
+                    !grouped.contains(&spatial.entity)
+                        && coincides(bounds, spatial.bounds)
+                })
+                .map(|spatial| spatial.entity)
+                .collect();
+
+            if group.len() > 1 {
+                group.sort();
+                grouped.extend(group.iter().copied());
+                groups.push(group);
+            }
+        }
+
+        groups
     }
 
     /// Performs a spatial query for a given [`BoundingBox`]
     /// Returns an iterator with all [`SpatialEntity`] inhabiting the [`Space`]
     /// of the given BoundingBox
     /// The returned iterator can be empty
-    pub fn query_region<'this>(
-        &'this self,
-        region: BoundingBox,
-    ) -> impl Iterator<Item = SpatialEntity> + 'this {
-        self.quadtree.query(region.aabb()).into_iter().map(|q| *q.0)
+    pub fn query_region(
+        &self,
+        region: BoundingBox<DrawingSpace>,
+    ) -> impl Iterator<Item = SpatialEntity> {
+        let mut found = Vec::new();
+        self.quadtree.query(region, &mut found);
+
+        found.into_iter()
+    }
+
+    /// The area covered by each node in the quadtree, parents before their
+    /// children.
+    ///
+    /// This is mainly useful for visualising how the [`Space`] has been
+    /// partitioned (see [`crate::window::DebugOverlay`]).
+    pub fn node_rects(
+        &self,
+    ) -> impl Iterator<Item = BoundingBox<DrawingSpace>> {
+        let mut rects = Vec::new();
+        self.quadtree.node_rects(&mut rects);
+
+        rects.into_iter()
     }
 
     /// Clears the [`Space`] of all [`SpatialEntity`]
     pub fn clear(&mut self) {
         // Re-use old size
-        let size = self.quadtree.bounding_box();
-        self.quadtree = Self::tree_with_world_size(size);
-        self.ids.clear();
+        self.quadtree = Node::new(self.quadtree.bounds, 0);
+        self.bounds.clear();
     }
 
     /// Resizes the inner quadtree to the given **bigger** size
@@ -218,42 +251,385 @@ impl Space {
     /// # Panics
     /// Panics if the size given is not bigger then the initial bounding_box of
     /// the [`Space`]
-    pub fn resize(&mut self, size: impl Spatial<f64>) {
-        if self.quadtree.bounding_box().contains_rect(&size.aabb()) {
+    pub fn resize(&mut self, size: BoundingBox<DrawingSpace>) {
+        if self.quadtree.bounds.fully_contains(size) {
             panic!("Space.resize() ERROR: Size to resize to is smaller then the tree!")
         }
-        let spatial_entities: Vec<_> = self.iter().collect();
 
-        self.clear();
+        self.quadtree = Node::new(size, 0);
 
-        self.quadtree = Self::tree_with_world_size(size);
-        for spatial_entity in spatial_entities {
-            let item_id = self.insert_entity(spatial_entity);
-            self.ids.insert(spatial_entity.entity, item_id);
+        for (&entity, &bounds) in &self.bounds {
+            self.quadtree.insert(SpatialEntity::new(bounds, entity));
+        }
+    }
+}
+
+fn is_finite(bounds: BoundingBox<DrawingSpace>) -> bool {
+    [
+        bounds.min_x(),
+        bounds.min_y(),
+        bounds.max_x(),
+        bounds.max_y(),
+    ]
+    .iter()
+    .all(|coordinate| coordinate.is_finite())
+}
+
+/// A single node in the quadtree.
+///
+/// Items are pushed down into the smallest child which fully contains them,
+/// so anything straddling a child's border will stay on the parent.
+#[derive(Debug)]
+struct Node {
+    bounds: BoundingBox<DrawingSpace>,
+    depth: usize,
+    items: Vec<SpatialEntity>,
+    children: Option<Box<[Node; 4]>>,
+}
+
+impl Node {
+    fn new(bounds: BoundingBox<DrawingSpace>, depth: usize) -> Node {
+        Node {
+            bounds,
+            depth,
+            items: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn insert(&mut self, spatial: SpatialEntity) {
+        if let Some(child) = self.child_containing_mut(spatial.bounds) {
+            child.insert(spatial);
+            return;
+        }
+
+        self.items.push(spatial);
+
+        if self.children.is_none()
+            && self.items.len() > Space::TREE_MAX_CHILDREN
+            && self.depth < Space::TREE_MAX_DEPTH
+        {
+            self.split();
+        }
+    }
+
+    fn split(&mut self) {
+        let centre = self.bounds.bottom_left() + self.bounds.diagonal() / 2.0;
+        let depth = self.depth + 1;
+        let corners = [
+            self.bounds.bottom_left(),
+            self.bounds.bottom_right(),
+            self.bounds.top_right(),
+            self.bounds.top_left(),
+        ];
+        self.children = Some(Box::new([
+            Node::new(BoundingBox::new(centre, corners[0]), depth),
+            Node::new(BoundingBox::new(centre, corners[1]), depth),
+            Node::new(BoundingBox::new(centre, corners[2]), depth),
+            Node::new(BoundingBox::new(centre, corners[3]), depth),
+        ]));
+
+        for spatial in std::mem::take(&mut self.items) {
+            self.insert(spatial);
+        }
+    }
+
+    fn child_containing_mut(
+        &mut self,
+        bounds: BoundingBox<DrawingSpace>,
+    ) -> Option<&mut Node> {
+        self.children
+            .as_mut()?
+            .iter_mut()
+            .find(|child| child.bounds.fully_contains(bounds))
+    }
+
+    fn remove(&mut self, entity: Entity, bounds: BoundingBox<DrawingSpace>) {
+        // items always take the same path through the tree, so we only need
+        // to look where it would have been inserted
+        match self.child_containing_mut(bounds) {
+            Some(child) => child.remove(entity, bounds),
+            None => self.items.retain(|item| item.entity != entity),
+        }
+    }
+
+    fn query(
+        &self,
+        region: BoundingBox<DrawingSpace>,
+        found: &mut Vec<SpatialEntity>,
+    ) {
+        if !self.bounds.intersects_with(region) {
+            return;
+        }
+
+        found.extend(
+            self.items
+                .iter()
+                .filter(|item| item.bounds.intersects_with(region))
+                .copied(),
+        );
+
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.query(region, found);
+            }
+        }
+    }
+
+    fn node_rects(&self, rects: &mut Vec<BoundingBox<DrawingSpace>>) {
+        rects.push(self.bounds);
+
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.node_rects(rects);
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{
-        components::{BoundingBox, Space},
-        Point,
+        algorithms::Bounded,
+        components::{Geometry, Layer, Name},
+        CanvasSpace, Line, Vector,
     };
+    use euclid::Scale;
+    use specs::prelude::*;
 
     #[test]
     fn space_should_resize() {
         let mut space = Space::default();
-        assert_eq!(
-            space.quadtree.bounding_box().max_x() as f64,
-            Space::WORLD_RADIUS
-        );
+        assert_eq!(space.quadtree.bounds.max_x(), Space::WORLD_RADIUS);
         let new_radius = 2_000_000.0;
         let new_size = BoundingBox::new(
             Point::new(-new_radius, -new_radius),
             Point::new(new_radius, new_radius),
         );
         space.resize(new_size);
-        assert_eq!(space.quadtree.bounding_box().max_x() as f64, new_radius);
+        assert_eq!(space.quadtree.bounds.max_x(), new_radius);
+    }
+
+    #[test]
+    fn non_finite_bounds_are_skipped() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        let ent = world.create_entity().build();
+        space.modify(SpatialEntity::new(
+            BoundingBox::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)),
+            ent,
+        ));
+
+        for &bad in &[f64::INFINITY, f64::NEG_INFINITY] {
+            let bounds =
+                BoundingBox::new(Point::new(0.0, 0.0), Point::new(bad, 1.0));
+            space.modify(SpatialEntity::new(bounds, ent));
+        }
+
+        assert!(space.is_empty());
+        assert_eq!(space.quadtree.bounds, Space::default_world_size());
+    }
+
+    #[test]
+    fn total_bounds_of_everything_in_the_space() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        assert_eq!(space.total_bounds(), None);
+
+        let first = world.create_entity().build();
+        let second = world.create_entity().build();
+        space.modify(SpatialEntity::new(
+            BoundingBox::new(Point::new(-5.0, 0.0), Point::new(1.0, 1.0)),
+            first,
+        ));
+        space.modify(SpatialEntity::new(
+            BoundingBox::new(Point::new(2.0, 3.0), Point::new(4.0, 10.0)),
+            second,
+        ));
+
+        assert_eq!(
+            space.total_bounds(),
+            Some(BoundingBox::new(
+                Point::new(-5.0, 0.0),
+                Point::new(4.0, 10.0)
+            ))
+        );
+
+        space.remove(second);
+        assert_eq!(
+            space.total_bounds(),
+            Some(BoundingBox::new(
+                Point::new(-5.0, 0.0),
+                Point::new(1.0, 1.0)
+            ))
+        );
+    }
+
+    #[test]
+    fn query_a_region_after_the_tree_has_split() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        let mut inside = Vec::new();
+
+        for i in 0..100 {
+            let ent = world.create_entity().build();
+            let corner = Point::new(i as f64 * 10.0, 0.0);
+            let bounds =
+                BoundingBox::new(corner, corner + Vector::new(1.0, 1.0));
+            space.modify(SpatialEntity::new(bounds, ent));
+
+            if i < 5 {
+                inside.push(ent);
+            }
+        }
+
+        let region =
+            BoundingBox::new(Point::new(-1.0, -1.0), Point::new(45.0, 5.0));
+        let mut got: Vec<_> =
+            space.query_region(region).map(|s| s.entity).collect();
+        got.sort();
+
+        assert!(space.quadtree.children.is_some());
+        assert_eq!(space.len(), 100);
+        assert_eq!(got, inside);
+    }
+
+    #[test]
+    fn node_rects_cover_the_whole_tree() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        assert_eq!(
+            space.node_rects().collect::<Vec<_>>(),
+            vec![Space::default_world_size()]
+        );
+
+        for i in 0..=Space::TREE_MAX_CHILDREN {
+            let ent = world.create_entity().build();
+            // spread things across all four quadrants
+            let x = if i % 2 == 0 { -100.0 } else { 100.0 };
+            let y = if i % 4 < 2 { -100.0 } else { 100.0 };
+            let corner = Point::new(x + i as f64, y);
+            let bounds =
+                BoundingBox::new(corner, corner + Vector::new(0.5, 0.5));
+            space.modify(SpatialEntity::new(bounds, ent));
+        }
+
+        let rects: Vec<_> = space.node_rects().collect();
+
+        // the root was split into quadrants
+        assert_eq!(rects.len(), 5);
+        assert_eq!(rects[0], Space::default_world_size());
+        for quadrant in &rects[1..] {
+            assert!(rects[0].fully_contains(*quadrant));
+            assert_eq!(quadrant.area(), rects[0].area() / 4.0);
+        }
+    }
+
+    #[test]
+    fn pixel_pick_radius_is_the_same_at_any_zoom() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        let ent = world.create_entity().build();
+        let location = Point::new(10.0, 10.0);
+        space.modify(SpatialEntity::new(
+            BoundingBox::new(location, location),
+            ent,
+        ));
+        let pick_radius = euclid::Length::<f64, CanvasSpace>::new(3.0);
+
+        for &zoom in &[1.0, 10.0] {
+            let pixels_per_drawing_unit = Scale::new(zoom);
+            let radius = pick_radius / pixels_per_drawing_unit;
+            // 2 pixels away is inside the pick radius, 4 pixels isn't
+            let near = location + Vector::new(2.0 / zoom, 0.0);
+            let far = location + Vector::new(4.0 / zoom, 0.0);
+
+            assert_eq!(space.query_point(near, radius).count(), 1);
+            assert_eq!(space.query_point(far, radius).count(), 0);
+        }
+    }
+
+    #[test]
+    fn sort_overlapping_entities_by_their_actual_distance() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        // two diagonal lines with identical bounding boxes
+        let lines = vec![
+            Line::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0)),
+            Line::new(Point::new(0.0, 10.0), Point::new(10.0, 0.0)),
+        ];
+        let mut space = Space::default();
+        let mut entities = Vec::new();
+        for line in lines {
+            let ent = world
+                .create_entity()
+                .with(DrawingObject::new(Geometry::Line(line), layer))
+                .build();
+            space.modify(SpatialEntity::new(line.bounding_box(), ent));
+            entities.push(ent);
+        }
+
+        let near_first = Point::new(2.0, 2.5);
+        let near_second = Point::new(2.0, 7.5);
+        let radius = Length::new(20.0);
+        let drawing_objects = world.read_storage();
+
+        let got: Vec<_> = space
+            .query_point_sorted(near_first, radius, &drawing_objects)
+            .into_iter()
+            .map(|s| s.entity)
+            .collect();
+        assert_eq!(got, entities);
+
+        let got: Vec<_> = space
+            .query_point_sorted(near_second, radius, &drawing_objects)
+            .into_iter()
+            .map(|s| s.entity)
+            .collect();
+        assert_eq!(got, vec![entities[1], entities[0]]);
+    }
+
+    #[test]
+    fn find_coincident_points() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        let mut add = |location: Point| {
+            let ent = world.create_entity().build();
+            space.modify(SpatialEntity::new(
+                BoundingBox::new(location, location),
+                ent,
+            ));
+            ent
+        };
+        let first = add(Point::new(1.0, 1.0));
+        let _lonely = add(Point::new(5.0, 5.0));
+        let second = add(Point::new(1.0, 1.005));
+        let third = add(Point::new(10.0, 0.0));
+        let fourth = add(Point::new(10.0, 0.0));
+        let _too_far = add(Point::new(1.0, 1.5));
+
+        let got = space.find_coincident(Length::new(0.01));
+
+        assert_eq!(got, vec![vec![first, second], vec![third, fourth]]);
+    }
+
+    #[test]
+    fn overlapping_but_different_bounds_arent_coincident() {
+        let mut world = World::new();
+        let mut space = Space::default();
+        let big =
+            BoundingBox::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+        let small =
+            BoundingBox::new(Point::new(4.0, 4.0), Point::new(6.0, 6.0));
+        space.modify(SpatialEntity::new(big, world.create_entity().build()));
+        space.modify(SpatialEntity::new(small, world.create_entity().build()));
+
+        assert!(space.find_coincident(Length::new(0.1)).is_empty());
     }
 }
@@ -1,7 +1,11 @@
-use crate::components::Dimension;
-use piet::Color;
+use crate::components::{Dimension, DrawingUnit};
+use piet::{Color, LineCap, LineJoin, StrokeStyle};
 use specs::prelude::*;
 use specs_derive::Component;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
 
 #[derive(Debug, Clone, Component)]
 #[storage(DenseVecStorage)]
@@ -24,6 +28,20 @@ impl Default for PointStyle {
 pub struct LineStyle {
     pub stroke: Color,
     pub width: Dimension,
+    /// How the ends of a line are drawn.
+    pub cap: LineCap,
+    /// How corners are drawn where two segments of a path meet.
+    pub join: LineJoin,
+}
+
+impl LineStyle {
+    /// The [`StrokeStyle`] used when drawing with this [`LineStyle`].
+    pub fn stroke_style(&self) -> StrokeStyle {
+        let mut style = StrokeStyle::new();
+        style.set_line_cap(self.cap);
+        style.set_line_join(self.join);
+        style
+    }
 }
 
 impl Default for LineStyle {
@@ -31,6 +49,8 @@ impl Default for LineStyle {
         LineStyle {
             stroke: Color::BLACK,
             width: Dimension::default(),
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
         }
     }
 }
@@ -39,12 +59,142 @@ impl Default for LineStyle {
 #[storage(HashMapStorage)]
 pub struct WindowStyle {
     pub background_colour: Color,
+    /// The real-world length each drawing unit represents.
+    pub drawing_unit: DrawingUnit,
+    /// The colour used when drawing the x and y axes.
+    pub axes_colour: Color,
+    /// Should the x and y axes be drawn through the origin?
+    pub show_axes: bool,
+    /// How far (in pixels) a curve may deviate from the real shape when it
+    /// gets approximated for drawing.
+    pub curve_tolerance: f64,
+    /// The colour used to highlight the object under the cursor (see
+    /// [`crate::components::Hovered`]).
+    pub hover_colour: Color,
 }
 
 impl Default for WindowStyle {
     fn default() -> WindowStyle {
         WindowStyle {
             background_colour: Color::WHITE,
+            drawing_unit: DrawingUnit::default(),
+            axes_colour: Color::grey8(0xc0),
+            show_axes: false,
+            curve_tolerance: 0.1,
+            hover_colour: Color::rgb8(0x66, 0xb2, 0xff),
+        }
+    }
+}
+
+/// Format a [`Color`] as a hex string (e.g. `"#ff8000"`).
+///
+/// The alpha channel is only included (e.g. `"#ff800080"`) when the colour
+/// isn't fully opaque.
+pub fn colour_to_hex(colour: &Color) -> String {
+    let (r, g, b, a) = colour.as_rgba8();
+
+    if a == 0xff {
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    } else {
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
+
+/// Parse a [`Color`] from a `#rrggbb` or `#rrggbbaa` hex string.
+pub fn colour_from_hex(hex: &str) -> Result<Color, ParseColourError> {
+    let digits = hex.strip_prefix('#').ok_or(ParseColourError::MissingHash)?;
+
+    if digits.len() != 6 && digits.len() != 8 {
+        return Err(ParseColourError::InvalidLength(digits.len()));
+    }
+
+    // from_str_radix() would also accept a leading "+"
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseColourError::InvalidDigits(digits.to_string()));
+    }
+
+    let rgba =
+        u32::from_str_radix(digits, 16).expect("All characters are hex digits");
+
+    if digits.len() == 6 {
+        Ok(Color::from_rgba32_u32(rgba << 8 | 0xff))
+    } else {
+        Ok(Color::from_rgba32_u32(rgba))
+    }
+}
+
+/// The error returned by [`colour_from_hex()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColourError {
+    /// Hex colours must start with a `#`.
+    MissingHash,
+    /// There should be either 6 or 8 hex digits.
+    InvalidLength(usize),
+    /// The string contained something other than hex digits.
+    InvalidDigits(String),
+}
+
+impl Display for ParseColourError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColourError::MissingHash => {
+                write!(f, "hex colours should start with a \"#\"")
+            },
+            ParseColourError::InvalidLength(len) => write!(
+                f,
+                "expected 6 or 8 hex digits (\"#rrggbb\" or \"#rrggbbaa\") but found {}",
+                len
+            ),
+            ParseColourError::InvalidDigits(digits) => {
+                write!(f, "\"{}\" isn't a valid hex number", digits)
+            },
+        }
+    }
+}
+
+impl Error for ParseColourError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_an_opaque_colour() {
+        let colour = Color::rgb8(0xff, 0x80, 0x00);
+
+        let hex = colour_to_hex(&colour);
+
+        assert_eq!(hex, "#ff8000");
+        assert_eq!(
+            colour_from_hex(&hex).unwrap().as_rgba_u32(),
+            colour.as_rgba_u32()
+        );
+    }
+
+    #[test]
+    fn round_trip_a_translucent_colour() {
+        let colour = Color::rgba8(0x12, 0x34, 0x56, 0x78);
+
+        let hex = colour_to_hex(&colour);
+
+        assert_eq!(hex, "#12345678");
+        assert_eq!(
+            colour_from_hex(&hex).unwrap().as_rgba_u32(),
+            colour.as_rgba_u32()
+        );
+    }
+
+    #[test]
+    fn invalid_hex_strings_are_rejected() {
+        let inputs = vec![
+            ("ff8000", ParseColourError::MissingHash),
+            ("#ff80", ParseColourError::InvalidLength(4)),
+            ("#gg8000", ParseColourError::InvalidDigits("gg8000".into())),
+            ("#+12345", ParseColourError::InvalidDigits("+12345".into())),
+        ];
+
+        for (input, expected) in inputs {
+            assert_eq!(colour_from_hex(input).unwrap_err(), expected);
         }
     }
 }
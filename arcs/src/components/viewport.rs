@@ -1,5 +1,8 @@
-use crate::{algorithms::Translate, CanvasSpace, DrawingSpace, Point, Vector};
-use euclid::Scale;
+use crate::{
+    algorithms::Translate, Angle, BoundingBox, CanvasSpace, DrawingSpace,
+    Point, Vector,
+};
+use euclid::{Rotation2D, Scale, Size2D, Vector2D};
 use specs::prelude::*;
 use specs_derive::Component;
 
@@ -10,6 +13,85 @@ pub struct Viewport {
     pub centre: Point,
     /// The number of pixels each drawing unit should take up on the screen.
     pub pixels_per_drawing_unit: Scale<f64, DrawingSpace, CanvasSpace>,
+    /// How far the view is rotated (anti-clockwise) around the
+    /// [`Viewport::centre`], so the canvas's x-axis lines up with this
+    /// direction in the drawing.
+    pub rotation: Angle,
+    /// Does the drawing's y-axis point up the screen?
+    ///
+    /// This is normally `true`. Set it to `false` for data which is already
+    /// in screen coordinates (y pointing down), so it can be displayed without
+    /// flipping it first.
+    pub y_up: bool,
+}
+
+impl Viewport {
+    /// Centre the [`Viewport`] on some [`BoundingBox`] and zoom so it fills
+    /// as much of the window as possible.
+    ///
+    /// The [`Viewport::rotation`] is taken into account, so a rotated view
+    /// will fit the box around the bounds as they appear on the screen.
+    ///
+    /// If the bounds have no width or height (e.g. a single point) we can't
+    /// figure out a zoom level, so the current one is kept.
+    pub fn fit_to_bounds(
+        &mut self,
+        bounds: BoundingBox<DrawingSpace>,
+        window_size: Size2D<f64, CanvasSpace>,
+    ) {
+        self.centre = bounds.bottom_left() + bounds.diagonal() / 2.0;
+
+        let (sin, cos) = self.rotation.sin_cos();
+        let (width, height) = (bounds.width().get(), bounds.height().get());
+        let width_on_screen = (width * cos).abs() + (height * sin).abs();
+        let height_on_screen = (width * sin).abs() + (height * cos).abs();
+
+        let horizontal = window_size.width / width_on_screen;
+        let vertical = window_size.height / height_on_screen;
+        let pixels_per_drawing_unit = f64::min(horizontal, vertical);
+
+        if pixels_per_drawing_unit.is_finite() && pixels_per_drawing_unit > 0.0
+        {
+            self.pixels_per_drawing_unit = Scale::new(pixels_per_drawing_unit);
+        }
+    }
+
+    /// Convert a tolerance in pixels to drawing units.
+    ///
+    /// This is useful when flattening curves in drawing space (e.g. with
+    /// [`crate::algorithms::Approximate`]) so the facets stay too small to
+    /// see, regardless of how far you are zoomed in.
+    pub fn tolerance_in_drawing_units(&self, pixels: f64) -> f64 {
+        pixels / self.pixels_per_drawing_unit.get()
+    }
+
+    /// The direction of the canvas's y-axis in drawing space, `-1.0` when the
+    /// y-axis needs to be flipped (i.e. [`Viewport::y_up`]) and `1.0`
+    /// otherwise.
+    pub(crate) fn y_axis_direction(&self) -> f64 {
+        // the canvas's y-axis points down
+        if self.y_up {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Move the [`Viewport`] by some number of pixels on the screen.
+    ///
+    /// Positive x moves the view to the right and positive y moves it down,
+    /// the same as the canvas axes. That means a click-and-drag handler will
+    /// normally pass the *negated* mouse movement so the drawing follows the
+    /// cursor.
+    pub fn pan_by_pixels(&mut self, delta: Vector2D<f64, CanvasSpace>) {
+        let rotation: Rotation2D<f64, DrawingSpace, DrawingSpace> =
+            Rotation2D::new(self.rotation);
+        let delta = Vector::new(delta.x, self.y_axis_direction() * delta.y);
+        let displacement = rotation
+            .transform_vector(delta / self.pixels_per_drawing_unit.get());
+
+        self.translate(displacement);
+    }
 }
 
 impl crate::algorithms::Scale for Viewport {
@@ -20,6 +102,23 @@ impl crate::algorithms::Scale for Viewport {
             self.pixels_per_drawing_unit.get() / scale_factor,
         );
     }
+
+    /// Zoom the viewport while keeping `pivot` (in drawing units) at the
+    /// same spot on the screen, e.g. to zoom towards the cursor.
+    fn scale_about<S>(
+        &mut self,
+        scale_factor: f64,
+        pivot: euclid::Point2D<f64, S>,
+    ) where
+        Self: Translate<S>,
+    {
+        // we only implement Translate<DrawingSpace>, so S is always
+        // DrawingSpace
+        let centre = euclid::Point2D::new(self.centre.x, self.centre.y);
+
+        self.scale(scale_factor);
+        self.translate((pivot - centre) * (1.0 - scale_factor));
+    }
 }
 
 impl Translate<DrawingSpace> for Viewport {
@@ -27,3 +126,151 @@ impl Translate<DrawingSpace> for Viewport {
         self.centre.translate(displacement);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::Scale as _;
+    use euclid::approxeq::ApproxEq;
+
+    #[test]
+    fn fit_a_wide_drawing_to_the_window() {
+        let mut viewport = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(1.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+        let bounds =
+            BoundingBox::new(Point::new(10.0, 0.0), Point::new(30.0, 5.0));
+
+        viewport.fit_to_bounds(bounds, Size2D::new(400.0, 300.0));
+
+        assert_eq!(viewport.centre, Point::new(20.0, 2.5));
+        assert_eq!(viewport.pixels_per_drawing_unit, Scale::new(20.0));
+    }
+
+    #[test]
+    fn fit_a_wide_drawing_to_a_rotated_window() {
+        let mut viewport = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(1.0),
+            rotation: Angle::degrees(90.0),
+            y_up: true,
+        };
+        let bounds =
+            BoundingBox::new(Point::new(10.0, 0.0), Point::new(30.0, 5.0));
+
+        viewport.fit_to_bounds(bounds, Size2D::new(400.0, 300.0));
+
+        // the drawing's x-axis now runs up the screen
+        assert_eq!(viewport.centre, Point::new(20.0, 2.5));
+        assert!(viewport.pixels_per_drawing_unit.get().approx_eq(&15.0));
+    }
+
+    #[test]
+    fn fitting_to_a_single_point_keeps_the_zoom_level() {
+        let mut viewport = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(3.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+        let point = Point::new(1.0, 2.0);
+
+        viewport.fit_to_bounds(
+            BoundingBox::new(point, point),
+            Size2D::new(1.0, 1.0),
+        );
+
+        assert_eq!(viewport.centre, point);
+        assert_eq!(viewport.pixels_per_drawing_unit, Scale::new(3.0));
+    }
+
+    #[test]
+    fn zooming_about_a_point_keeps_it_still_on_screen() {
+        let mut viewport = Viewport {
+            centre: Point::new(10.0, 10.0),
+            pixels_per_drawing_unit: Scale::new(2.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+        let pivot = Point::new(20.0, 5.0);
+        let on_screen = |viewport: &Viewport| {
+            (pivot - viewport.centre) * viewport.pixels_per_drawing_unit.get()
+        };
+        let original_location = on_screen(&viewport);
+
+        viewport.scale_about(0.5, pivot);
+
+        assert_eq!(viewport.pixels_per_drawing_unit, Scale::new(4.0));
+        assert_eq!(on_screen(&viewport), original_location);
+    }
+
+    #[test]
+    fn pan_right_by_a_window_width() {
+        let window_width = 400.0;
+        let mut viewport = Viewport {
+            centre: Point::new(10.0, 10.0),
+            pixels_per_drawing_unit: Scale::new(4.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+
+        viewport.pan_by_pixels(Vector2D::new(window_width, 0.0));
+
+        assert_eq!(viewport.centre, Point::new(110.0, 10.0));
+    }
+
+    #[test]
+    fn panning_down_the_screen_moves_down_the_drawing() {
+        let mut viewport = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(2.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+
+        viewport.pan_by_pixels(Vector2D::new(0.0, 20.0));
+
+        assert_eq!(viewport.centre, Point::new(0.0, -10.0));
+    }
+
+    #[test]
+    fn panning_matches_the_canvas_transform() {
+        let window = Size2D::new(300.0, 200.0);
+
+        for &y_up in &[true, false] {
+            let mut viewport = Viewport {
+                centre: Point::new(3.0, -2.0),
+                pixels_per_drawing_unit: Scale::new(5.0),
+                rotation: Angle::degrees(30.0),
+                y_up,
+            };
+            let delta = Vector2D::new(12.0, -7.0);
+            let to_drawing =
+                crate::window::transform_to_drawing_space(&viewport, window);
+            let middle = euclid::Point2D::new(150.0, 100.0);
+            let expected = to_drawing.transform_point(middle + delta);
+
+            viewport.pan_by_pixels(delta);
+
+            assert!(viewport.centre.approx_eq(&expected));
+        }
+    }
+
+    #[test]
+    fn tolerances_shrink_when_zoomed_in() {
+        let mut viewport = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(4.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+        assert_eq!(viewport.tolerance_in_drawing_units(0.5), 0.125);
+
+        viewport.scale(0.5);
+
+        assert_eq!(viewport.tolerance_in_drawing_units(0.5), 0.0625);
+    }
+}
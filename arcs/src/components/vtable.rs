@@ -1,19 +1,23 @@
-use specs::{Component, World, WorldExt};
-use std::any;
+use specs::{Component, Entity, World, WorldExt};
+use std::{
+    any::{self, Any},
+    fmt::{self, Debug, Formatter},
+};
 
 /// Functions for working with generic [`Component`]s without needing to drag a
 /// type variable around.
 #[derive(Copy, Clone)]
-pub(crate) struct ComponentVtable {
+pub struct ComponentVtable {
     name: &'static str,
     register: fn(world: &mut World),
+    snapshot: fn(world: &World, entity: Entity) -> Option<Box<dyn Any>>,
 }
 
 impl ComponentVtable {
     /// Create the [`ComponentVtable`] corresponding to a particular type.
     pub fn for_type<T>() -> Self
     where
-        T: Component,
+        T: Component + Clone,
         T::Storage: Default,
     {
         ComponentVtable {
@@ -21,6 +25,11 @@ impl ComponentVtable {
             register: |world| {
                 world.register::<T>();
             },
+            snapshot: |world, entity| {
+                world.read_storage::<T>().get(entity).map(|component| {
+                    Box::new(component.clone()) as Box<dyn Any>
+                })
+            },
         }
     }
 
@@ -29,4 +38,23 @@ impl ComponentVtable {
 
     /// Register this component with the [`World`].
     pub(crate) fn register(&self, world: &mut World) { (self.register)(world); }
+
+    /// Get a copy of this component if it is attached to `entity`.
+    ///
+    /// The component must already be registered with the [`World`].
+    pub fn snapshot(
+        &self,
+        world: &World,
+        entity: Entity,
+    ) -> Option<Box<dyn Any>> {
+        (self.snapshot)(world, entity)
+    }
+}
+
+impl Debug for ComponentVtable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentVtable")
+            .field("name", &self.name)
+            .finish()
+    }
 }
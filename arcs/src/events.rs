@@ -0,0 +1,230 @@
+//! Notifications about changes to the drawing.
+
+use crate::components::DrawingObject;
+use specs::{prelude::*, world::Index};
+use std::collections::HashMap;
+
+/// Something which happened to a [`DrawingObject`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DrawingChange {
+    /// A [`DrawingObject`] was added to this entity.
+    Inserted(Entity),
+    /// The entity's [`DrawingObject`] was modified.
+    Modified(Entity),
+    /// The entity's [`DrawingObject`] was removed.
+    ///
+    /// The entity itself may have been deleted by the time you see this, so
+    /// check [`specs::world::EntitiesRes::is_alive()`] before using it.
+    Removed(Entity),
+}
+
+impl DrawingChange {
+    /// The entity which was changed.
+    pub fn entity(self) -> Entity {
+        match self {
+            DrawingChange::Inserted(entity)
+            | DrawingChange::Modified(entity)
+            | DrawingChange::Removed(entity) => entity,
+        }
+    }
+}
+
+/// A resource containing every [`DrawingChange`] seen by the
+/// [`DrawingChanges`] system, in the order they happened.
+///
+/// Application code should [`DrawingChangeLog::drain()`] this every frame
+/// (e.g. to mark the document as dirty), otherwise it will keep growing.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrawingChangeLog {
+    changes: Vec<DrawingChange>,
+}
+
+impl DrawingChangeLog {
+    /// The changes which haven't been drained yet.
+    pub fn changes(&self) -> &[DrawingChange] { &self.changes }
+
+    /// Has anything changed since the log was last drained?
+    pub fn is_empty(&self) -> bool { self.changes.is_empty() }
+
+    /// Take every [`DrawingChange`] out of the log.
+    pub fn drain(&mut self) -> impl Iterator<Item = DrawingChange> + '_ {
+        self.changes.drain(..)
+    }
+}
+
+/// A [`System`] which records changes to [`DrawingObject`]s in the
+/// [`DrawingChangeLog`] resource.
+///
+/// This isn't part of [`crate::systems::register_background_tasks()`], so
+/// only applications which drain the [`DrawingChangeLog`] pay for it.
+///
+/// ```rust
+/// # use arcs::{events::{DrawingChangeLog, DrawingChanges}, systems};
+/// # use specs::prelude::*;
+/// let mut world = World::new();
+/// arcs::components::register(&mut world);
+/// let mut dispatcher = systems::register_background_tasks(
+///     DispatcherBuilder::new(),
+///     &world,
+/// )
+/// .with(DrawingChanges::new(&world), DrawingChanges::NAME, &[])
+/// .build();
+/// dispatcher.setup(&mut world);
+///
+/// // later, in the main loop
+/// dispatcher.dispatch(&world);
+/// for change in world.write_resource::<DrawingChangeLog>().drain() {
+///     println!("{:?}", change);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DrawingChanges {
+    changes: ReaderId<ComponentEvent>,
+    /// The [`Entity`] each [`DrawingObject`] was inserted on.
+    ///
+    /// Events only contain an [`Index`], and once an entity is deleted its
+    /// index can be handed out again with a new generation.
+    owners: HashMap<Index, Entity>,
+}
+
+impl DrawingChanges {
+    pub const NAME: &'static str = module_path!();
+
+    pub fn new(world: &World) -> Self {
+        let changes = world.write_storage::<DrawingObject>().register_reader();
+        let owners =
+            (&world.entities(), &world.read_storage::<DrawingObject>())
+                .join()
+                .map(|(ent, _)| (ent.id(), ent))
+                .collect();
+
+        DrawingChanges { changes, owners }
+    }
+}
+
+impl<'world> System<'world> for DrawingChanges {
+    type SystemData = (
+        Entities<'world>,
+        ReadStorage<'world, DrawingObject>,
+        Write<'world, DrawingChangeLog>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, drawing_objects, mut log) = data;
+
+        for event in drawing_objects.channel().read(&mut self.changes) {
+            let change = match *event {
+                ComponentEvent::Inserted(id) => {
+                    let entity = entities.entity(id);
+                    self.owners.insert(id, entity);
+                    DrawingChange::Inserted(entity)
+                },
+                ComponentEvent::Modified(id) => {
+                    let entity = self
+                        .owners
+                        .get(&id)
+                        .copied()
+                        .unwrap_or_else(|| entities.entity(id));
+                    DrawingChange::Modified(entity)
+                },
+                ComponentEvent::Removed(id) => {
+                    let entity = self
+                        .owners
+                        .remove(&id)
+                        .unwrap_or_else(|| entities.entity(id));
+                    DrawingChange::Removed(entity)
+                },
+            };
+
+            log.changes.push(change);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{Geometry, Layer, Name},
+        Point,
+    };
+
+    #[test]
+    fn record_changes_to_drawing_objects() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        let mut system = DrawingChanges::new(&world);
+        System::setup(&mut system, &mut world);
+
+        let point = world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .build();
+        world
+            .write_storage::<DrawingObject>()
+            .get_mut(point)
+            .unwrap()
+            .set_geometry(Geometry::Point(Point::new(1.0, 1.0)));
+        world.write_storage::<DrawingObject>().remove(point);
+        system.run_now(&world);
+
+        let got: Vec<_> =
+            world.write_resource::<DrawingChangeLog>().drain().collect();
+        assert_eq!(
+            got,
+            vec![
+                DrawingChange::Inserted(point),
+                DrawingChange::Modified(point),
+                DrawingChange::Removed(point),
+            ]
+        );
+        assert!(world.read_resource::<DrawingChangeLog>().is_empty());
+
+        // nothing new happened, so there's nothing to report
+        system.run_now(&world);
+        assert!(world.read_resource::<DrawingChangeLog>().is_empty());
+    }
+
+    #[test]
+    fn removals_report_the_deleted_entity() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        let mut system = DrawingChanges::new(&world);
+        System::setup(&mut system, &mut world);
+        let first = world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .build();
+        system.run_now(&world);
+        let _ = world.write_resource::<DrawingChangeLog>().drain();
+
+        world.delete_entity(first).unwrap();
+        world.maintain();
+        let second = world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .build();
+        assert_eq!(second.id(), first.id());
+        system.run_now(&world);
+
+        let got: Vec<_> =
+            world.write_resource::<DrawingChangeLog>().drain().collect();
+        assert_eq!(
+            got,
+            vec![
+                DrawingChange::Removed(first),
+                DrawingChange::Inserted(second)
+            ]
+        );
+    }
+}
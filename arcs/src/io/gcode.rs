@@ -0,0 +1,509 @@
+use crate::{
+    algorithms::{Approximate, Length},
+    components::{Construction, DrawingObject, DrawingUnit, Geometry, Layer},
+    Arc, EllipticalArc, Line, Point,
+};
+use euclid::approxeq::ApproxEq;
+use specs::prelude::*;
+
+/// Settings used when generating G-code with [`export_gcode()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GcodeOptions {
+    /// The real-world length each drawing unit represents.
+    pub drawing_unit: DrawingUnit,
+    /// How fast the tool should move while cutting, in output units (inches
+    /// for [`DrawingUnit::Inches`], otherwise millimetres) per minute.
+    pub feed_rate: f64,
+    /// The Z height to cut at.
+    pub cut_depth: f64,
+    /// The Z height to retract to before moving between contours.
+    pub safe_z: f64,
+    /// Should [`Geometry::Point`]s be emitted as `G81` drill cycles?
+    pub drill_points: bool,
+    /// How far (in drawing units) the toolpath may stray from a
+    /// [`Geometry::Ellipse`], which has to be cut as a series of `G1` moves.
+    pub tolerance: f64,
+}
+
+impl Default for GcodeOptions {
+    fn default() -> GcodeOptions {
+        GcodeOptions {
+            drawing_unit: DrawingUnit::default(),
+            feed_rate: 500.0,
+            cut_depth: -1.0,
+            safe_z: 5.0,
+            drill_points: true,
+            tolerance: 0.01,
+        }
+    }
+}
+
+/// Generate a G-code program which cuts out every line and arc in the
+/// drawing.
+///
+/// Lines become `G1` moves and arcs become `G2` (clockwise) or `G3`
+/// (anti-clockwise) moves. Ellipses have no G-code equivalent, so they are
+/// approximated by `G1` moves within [`GcodeOptions::tolerance`]. The tool
+/// retracts to [`GcodeOptions::safe_z`]
+/// whenever the next curve doesn't start where the previous one ended.
+///
+/// Layers are cut in order of increasing z-level. Hidden layers,
+/// [`Construction`] geometry, and dimensions are skipped.
+pub fn export_gcode(world: &World, opts: GcodeOptions) -> String {
+    let entities = world.entities();
+    let drawing_objects = world.read_storage::<DrawingObject>();
+    let layers = world.read_storage::<Layer>();
+    let construction = world.read_storage::<Construction>();
+
+    let mut objects: Vec<_> = (&entities, &drawing_objects, !&construction)
+        .join()
+        .filter_map(|(ent, obj, _)| {
+            let layer = layers.get(obj.layer)?;
+            if layer.visible {
+                Some((layer.z_level, ent, obj.geometry()))
+            } else {
+                None
+            }
+        })
+        .collect();
+    objects.sort_by_key(|&(z_level, ent, _)| (z_level, ent));
+
+    let mut program = Program::new(opts);
+    let mut holes = Vec::new();
+
+    for (_, _, geometry) in objects {
+        match *geometry {
+            Geometry::Line(line) => program.cut_line(line),
+            Geometry::Arc(arc) => program.cut_arc(arc),
+            Geometry::Ellipse(arc) => program.cut_ellipse(arc),
+            Geometry::Point(point) => holes.push(point),
+            Geometry::LinearDimension { .. } => {},
+        }
+    }
+
+    if program.opts.drill_points {
+        program.drill(&holes);
+    }
+
+    program.finish()
+}
+
+/// A G-code program which is being built up one move at a time.
+#[derive(Debug)]
+struct Program {
+    opts: GcodeOptions,
+    /// How many output units each drawing unit is.
+    scale: f64,
+    body: Vec<String>,
+    /// Where the tool is, if it's currently cutting.
+    position: Option<Point>,
+    cut_length: f64,
+}
+
+impl Program {
+    fn new(opts: GcodeOptions) -> Program {
+        let scale = match opts.drawing_unit {
+            DrawingUnit::Inches => 1.0,
+            other => other.millimetres(),
+        };
+
+        Program {
+            opts,
+            scale,
+            body: Vec::new(),
+            position: None,
+            cut_length: 0.0,
+        }
+    }
+
+    fn cut_line(&mut self, line: Line) {
+        let line = if self.is_at(line.end) && !self.is_at(line.start) {
+            Line::new(line.end, line.start)
+        } else {
+            line
+        };
+
+        self.plunge_at(line.start);
+        self.body.push(format!("G1 {}", self.xy(line.end)));
+        self.finish_move(line.end, line.length());
+    }
+
+    fn cut_arc(&mut self, arc: Arc) {
+        let arc = if self.is_at(arc.end()) && !self.is_at(arc.start()) {
+            arc.reverse()
+        } else {
+            arc
+        };
+
+        self.plunge_at(arc.start());
+        let command = if arc.is_clockwise() { "G2" } else { "G3" };
+        let offset = (arc.centre() - arc.start()) * self.scale;
+        self.body.push(format!(
+            "{} {} I{} J{}",
+            command,
+            self.xy(arc.end()),
+            number(offset.x),
+            number(offset.y)
+        ));
+        self.finish_move(arc.end(), arc.length());
+    }
+
+    fn cut_ellipse(&mut self, arc: EllipticalArc) {
+        let ellipse = arc.ellipse();
+
+        if ellipse.is_circle() {
+            // the parametric angle is measured from the rotated x-axis
+            return self.cut_arc(Arc::from_centre_radius(
+                ellipse.centre(),
+                ellipse.major_radius(),
+                arc.start_angle() + ellipse.rotation(),
+                arc.sweep_angle(),
+            ));
+        }
+
+        let arc = if self.is_at(arc.end()) && !self.is_at(arc.start()) {
+            arc.reverse()
+        } else {
+            arc
+        };
+
+        self.plunge_at(arc.start());
+        let mut previous = arc.start();
+        for point in arc.approximate(self.opts.tolerance).skip(1) {
+            self.body.push(format!("G1 {}", self.xy(point)));
+            self.finish_move(point, previous.distance_to(point));
+            previous = point;
+        }
+    }
+
+    fn drill(&mut self, holes: &[Point]) {
+        if holes.is_empty() {
+            return;
+        }
+
+        self.retract();
+        for &hole in holes {
+            self.body.push(format!(
+                "G81 {} Z{} R{} F{}",
+                self.xy(hole),
+                number(self.opts.cut_depth),
+                number(self.opts.safe_z),
+                number(self.opts.feed_rate)
+            ));
+        }
+        self.body.push(String::from("G80"));
+    }
+
+    /// Make sure the tool is cutting at `point`, retracting and moving there
+    /// if necessary.
+    fn plunge_at(&mut self, point: Point) {
+        if self.is_at(point) {
+            return;
+        }
+
+        self.retract();
+        self.body.push(format!("G0 {}", self.xy(point)));
+        self.body.push(format!(
+            "G1 Z{} F{}",
+            number(self.opts.cut_depth),
+            number(self.opts.feed_rate)
+        ));
+        self.position = Some(point);
+    }
+
+    fn retract(&mut self) {
+        if self.position.take().is_some() {
+            self.body.push(format!("G0 Z{}", number(self.opts.safe_z)));
+        }
+    }
+
+    fn finish_move(&mut self, end: Point, length: f64) {
+        self.position = Some(end);
+        self.cut_length += length * self.scale;
+    }
+
+    fn is_at(&self, point: Point) -> bool {
+        self.position.is_some_and(|p| p.approx_eq(&point))
+    }
+
+    fn xy(&self, point: Point) -> String {
+        format!(
+            "X{} Y{}",
+            number(point.x * self.scale),
+            number(point.y * self.scale)
+        )
+    }
+
+    fn finish(mut self) -> String {
+        self.retract();
+
+        let units = match self.opts.drawing_unit {
+            DrawingUnit::Inches => "G20",
+            _ => "G21",
+        };
+        let minutes = self.cut_length / self.opts.feed_rate;
+
+        let mut lines = vec![
+            format!("(Estimated cutting time: {:.1} minutes)", minutes),
+            format!("{} G90 G17", units),
+            format!("G0 Z{}", number(self.opts.safe_z)),
+        ];
+        lines.extend(self.body);
+        lines.push(String::from("M2"));
+
+        let mut program = lines.join("\n");
+        program.push('\n');
+        program
+    }
+}
+
+/// Format a number with a fixed precision, avoiding `-0.000`.
+fn number(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+
+    if formatted
+        .trim_start_matches('-')
+        .trim_matches(&['0', '.'][..])
+        .is_empty()
+    {
+        String::from("0.000")
+    } else {
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::Name, Angle, Ellipse};
+
+    fn world_with_layer() -> (World, Entity) {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+
+        (world, layer)
+    }
+
+    fn add(world: &mut World, layer: Entity, geometry: Geometry) -> Entity {
+        world
+            .create_entity()
+            .with(DrawingObject::new(geometry, layer))
+            .build()
+    }
+
+    #[test]
+    fn cut_a_connected_contour() {
+        let (mut world, layer) = world_with_layer();
+        add(
+            &mut world,
+            layer,
+            Geometry::Line(Line::new(Point::zero(), Point::new(10.0, 0.0))),
+        );
+        // an anticlockwise half circle from (10, 0) to (10, 10)
+        add(
+            &mut world,
+            layer,
+            Geometry::Arc(Arc::from_centre_radius(
+                Point::new(10.0, 5.0),
+                5.0,
+                -Angle::frac_pi_2(),
+                Angle::pi(),
+            )),
+        );
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        let expected = "\
+(Estimated cutting time: 0.1 minutes)
+G21 G90 G17
+G0 Z5.000
+G0 X0.000 Y0.000
+G1 Z-1.000 F500.000
+G1 X10.000 Y0.000
+G3 X10.000 Y10.000 I0.000 J5.000
+G0 Z5.000
+M2
+";
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn retract_between_disjoint_lines() {
+        let (mut world, layer) = world_with_layer();
+        add(
+            &mut world,
+            layer,
+            Geometry::Line(Line::new(Point::zero(), Point::new(1.0, 0.0))),
+        );
+        add(
+            &mut world,
+            layer,
+            Geometry::Line(Line::new(
+                Point::new(5.0, 5.0),
+                Point::new(6.0, 5.0),
+            )),
+        );
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        let retracts = got.lines().filter(|l| *l == "G0 Z5.000").count();
+        // once at the start, once between the lines, and once at the end
+        assert_eq!(retracts, 3);
+    }
+
+    #[test]
+    fn clockwise_arcs_use_g2() {
+        let (mut world, layer) = world_with_layer();
+        add(
+            &mut world,
+            layer,
+            Geometry::Arc(Arc::from_centre_radius(
+                Point::zero(),
+                1.0,
+                Angle::frac_pi_2(),
+                -Angle::frac_pi_2(),
+            )),
+        );
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        assert!(got.contains("G2 X1.000 Y0.000 I0.000 J-1.000"), "{}", got);
+    }
+
+    #[test]
+    fn ellipses_are_cut_as_straight_moves() {
+        let (mut world, layer) = world_with_layer();
+        let ellipse = Ellipse::new(Point::zero(), 20.0, 10.0, Angle::zero());
+        add(
+            &mut world,
+            layer,
+            Geometry::Ellipse(EllipticalArc::new(
+                ellipse,
+                Angle::zero(),
+                Angle::pi(),
+            )),
+        );
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        assert!(got.contains("G0 X20.000 Y0.000\n"));
+        assert!(got.contains("G1 X-20.000 Y0.000\n"));
+        assert!(
+            got.lines().filter(|line| line.starts_with("G1 X")).count() > 10
+        );
+        assert!(!got.contains("\nG2 ") && !got.contains("\nG3 "));
+    }
+
+    #[test]
+    fn circular_ellipses_are_cut_as_arcs() {
+        let (mut world, layer) = world_with_layer();
+        let circle =
+            Ellipse::new(Point::zero(), 10.0, 10.0, Angle::frac_pi_2());
+        add(
+            &mut world,
+            layer,
+            Geometry::Ellipse(EllipticalArc::new(
+                circle,
+                Angle::zero(),
+                -Angle::frac_pi_2(),
+            )),
+        );
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        assert!(got.contains("G0 X0.000 Y10.000\n"));
+        assert!(got.contains("G2 X10.000 Y0.000 I0.000 J-10.000\n"));
+    }
+
+    #[test]
+    fn points_become_drill_cycles() {
+        let (mut world, layer) = world_with_layer();
+        add(&mut world, layer, Geometry::Point(Point::new(2.0, 3.0)));
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        assert!(got.contains("G81 X2.000 Y3.000 Z-1.000 R5.000 F500.000"));
+        assert!(got.contains("G80"));
+    }
+
+    #[test]
+    fn skip_construction_geometry_and_hidden_layers() {
+        let (mut world, layer) = world_with_layer();
+        let hidden = Layer::create(
+            world.create_entity(),
+            Name::new("hidden"),
+            Layer {
+                z_level: 1,
+                visible: false,
+            },
+        );
+        let line =
+            Geometry::Line(Line::new(Point::zero(), Point::new(1.0, 0.0)));
+        add(&mut world, hidden, line.clone());
+        let construction = add(&mut world, layer, line);
+        world
+            .write_storage()
+            .insert(construction, Construction)
+            .unwrap();
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        assert!(!got.contains("G1 "), "{}", got);
+    }
+
+    #[test]
+    fn lines_are_ordered_by_z_level() {
+        let (mut world, bottom) = world_with_layer();
+        let top = Layer::create(
+            world.create_entity(),
+            Name::new("top"),
+            Layer {
+                z_level: 1,
+                visible: true,
+            },
+        );
+        add(
+            &mut world,
+            top,
+            Geometry::Line(Line::new(
+                Point::new(20.0, 0.0),
+                Point::new(30.0, 0.0),
+            )),
+        );
+        add(
+            &mut world,
+            bottom,
+            Geometry::Line(Line::new(Point::zero(), Point::new(10.0, 0.0))),
+        );
+
+        let got = export_gcode(&world, GcodeOptions::default());
+
+        let first = got.find("X10.000").unwrap();
+        let second = got.find("X30.000").unwrap();
+        assert!(first < second);
+    }
+
+    #[test]
+    fn metres_are_converted_to_millimetres() {
+        let (mut world, layer) = world_with_layer();
+        add(
+            &mut world,
+            layer,
+            Geometry::Line(Line::new(Point::zero(), Point::new(0.5, 0.0))),
+        );
+        let opts = GcodeOptions {
+            drawing_unit: DrawingUnit::Metres,
+            ..Default::default()
+        };
+
+        let got = export_gcode(&world, opts);
+
+        assert!(got.contains("G21"));
+        assert!(got.contains("G1 X500.000 Y0.000"));
+    }
+}
@@ -0,0 +1,6 @@
+//! Getting drawings into and out of a [`specs::World`].
+
+pub mod gcode;
+mod snapshot;
+
+pub use snapshot::Snapshot;
@@ -0,0 +1,275 @@
+use crate::components::{
+    Construction, DrawPriority, DrawingObject, Group, Layer, LineStyle, Name,
+    PointStyle,
+};
+use specs::prelude::*;
+use std::collections::HashMap;
+
+/// An in-memory copy of everything needed to redraw the drawing, used as a
+/// restore point (e.g. for autosave or "revert to last save").
+///
+/// This captures every [`Layer`] and [`DrawingObject`], along with their
+/// [`Name`]s, [`Group`]s, [`Construction`] markers, [`DrawPriority`]s, and
+/// any [`LineStyle`] or [`PointStyle`] overrides. Editor state like the
+/// current selection isn't captured, and the [`crate::window::Window`] is
+/// left alone.
+///
+/// # Note
+///
+/// Restoring creates new entities, so any [`Entity`] handles taken before
+/// [`Snapshot::restore()`] won't refer to the restored objects. This also
+/// means nothing will be [`crate::components::Selected`] afterwards.
+#[derive(Debug, Clone, Default)]
+pub struct Snapshot {
+    layers: Vec<Record<Layer>>,
+    drawing_objects: Vec<Record<DrawingObject>>,
+}
+
+impl Snapshot {
+    /// Take a snapshot of the drawing.
+    pub fn capture(world: &World) -> Snapshot {
+        let extras: Extras = world.system_data();
+
+        let layers = (&extras.entities, &world.read_storage::<Layer>())
+            .join()
+            .map(|(entity, layer)| extras.record(entity, layer.clone()))
+            .collect();
+        let drawing_objects =
+            (&extras.entities, &world.read_storage::<DrawingObject>())
+                .join()
+                .map(|(entity, obj)| extras.record(entity, obj.clone()))
+                .collect();
+
+        Snapshot {
+            layers,
+            drawing_objects,
+        }
+    }
+
+    /// Throw away the current drawing and replace it with the one from this
+    /// [`Snapshot`].
+    pub fn restore(&self, world: &mut World) {
+        let mut existing: Vec<Entity> = {
+            let entities = world.entities();
+            let layers = world.read_storage::<Layer>();
+            let drawing_objects = world.read_storage::<DrawingObject>();
+
+            (&entities, &layers)
+                .join()
+                .map(|(entity, _)| entity)
+                .chain(
+                    (&entities, &drawing_objects)
+                        .join()
+                        .map(|(entity, _)| entity),
+                )
+                .collect()
+        };
+        existing.sort();
+        existing.dedup();
+        world
+            .delete_entities(&existing)
+            .expect("These entities were all alive");
+
+        let mut new_entities = HashMap::new();
+
+        for record in &self.layers {
+            let entity = record.create(world.create_entity(), |layer| layer);
+            new_entities.insert(record.entity, entity);
+        }
+
+        for record in &self.drawing_objects {
+            let entity = record.create(world.create_entity(), |mut obj| {
+                obj.layer = remap(&new_entities, obj.layer);
+                obj
+            });
+            new_entities.insert(record.entity, entity);
+        }
+
+        // groups can refer to any entity, so wait until everything exists
+        let mut groups = world.write_storage::<Group>();
+        let memberships = self
+            .layers
+            .iter()
+            .map(|record| (record.entity, record.group))
+            .chain(
+                self.drawing_objects
+                    .iter()
+                    .map(|record| (record.entity, record.group)),
+            );
+
+        for (entity, group) in memberships {
+            if let Some(Group { parent }) = group {
+                let group = Group {
+                    parent: remap(&new_entities, parent),
+                };
+                groups
+                    .insert(new_entities[&entity], group)
+                    .expect("The entity was just created");
+            }
+        }
+    }
+}
+
+/// Find the restored version of an [`Entity`], leaving it unchanged if it
+/// wasn't part of the [`Snapshot`].
+fn remap(new_entities: &HashMap<Entity, Entity>, entity: Entity) -> Entity {
+    new_entities.get(&entity).copied().unwrap_or(entity)
+}
+
+/// A captured component and everything else attached to its [`Entity`].
+#[derive(Debug, Clone)]
+struct Record<T> {
+    entity: Entity,
+    component: T,
+    name: Option<Name>,
+    line_style: Option<LineStyle>,
+    point_style: Option<PointStyle>,
+    group: Option<Group>,
+    construction: bool,
+    draw_priority: Option<DrawPriority>,
+}
+
+impl<T: Clone + Component + Send + Sync> Record<T> {
+    fn create<F>(&self, builder: EntityBuilder<'_>, map: F) -> Entity
+    where
+        F: FnOnce(T) -> T,
+    {
+        let mut builder = builder.with(map(self.component.clone()));
+
+        if let Some(ref name) = self.name {
+            builder = builder.with(name.clone());
+        }
+        if let Some(ref line_style) = self.line_style {
+            builder = builder.with(line_style.clone());
+        }
+        if let Some(ref point_style) = self.point_style {
+            builder = builder.with(point_style.clone());
+        }
+        if self.construction {
+            builder = builder.with(Construction);
+        }
+        if let Some(draw_priority) = self.draw_priority {
+            builder = builder.with(draw_priority);
+        }
+
+        builder.build()
+    }
+}
+
+/// The optional components captured alongside each [`Record`].
+#[derive(shred_derive::SystemData)]
+struct Extras<'world> {
+    entities: Entities<'world>,
+    names: ReadStorage<'world, Name>,
+    line_styles: ReadStorage<'world, LineStyle>,
+    point_styles: ReadStorage<'world, PointStyle>,
+    groups: ReadStorage<'world, Group>,
+    construction: ReadStorage<'world, Construction>,
+    draw_priorities: ReadStorage<'world, DrawPriority>,
+}
+
+impl<'world> Extras<'world> {
+    fn record<T>(&self, entity: Entity, component: T) -> Record<T> {
+        Record {
+            entity,
+            component,
+            name: self.names.get(entity).cloned(),
+            line_style: self.line_styles.get(entity).cloned(),
+            point_style: self.point_styles.get(entity).cloned(),
+            group: self.groups.get(entity).copied(),
+            construction: self.construction.contains(entity),
+            draw_priority: self.draw_priorities.get(entity).copied(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::Geometry, Line, Point};
+    use piet::Color;
+
+    #[test]
+    fn restore_a_modified_drawing() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create_with_styles(
+            world.create_entity(),
+            Name::new("walls"),
+            Layer::default(),
+            LineStyle {
+                stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
+            },
+            PointStyle::default(),
+        );
+        let line = Line::new(Point::zero(), Point::new(10.0, 0.0));
+        let first = world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Line(line), layer))
+            .with(Name::new("first"))
+            .build();
+        let second = world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(1.0, 1.0)),
+                layer,
+            ))
+            .with(Group { parent: first })
+            .with(Construction)
+            .build();
+        let snapshot = Snapshot::capture(&world);
+
+        // mess up the drawing
+        world.delete_entity(second).unwrap();
+        world
+            .write_storage::<DrawingObject>()
+            .get_mut(first)
+            .unwrap()
+            .set_geometry(Geometry::Point(Point::zero()));
+        world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(5.0, 5.0)),
+                layer,
+            ))
+            .build();
+        world.maintain();
+
+        snapshot.restore(&mut world);
+        world.maintain();
+
+        let entities = world.entities();
+        let layers = world.read_storage::<Layer>();
+        let drawing_objects = world.read_storage::<DrawingObject>();
+        let names = world.read_storage::<Name>();
+        let line_styles = world.read_storage::<LineStyle>();
+        let groups = world.read_storage::<Group>();
+        let construction = world.read_storage::<Construction>();
+
+        let (new_layer, _) = (&entities, &layers).join().next().unwrap();
+        assert_eq!(layers.count(), 1);
+        assert_eq!(names.get(new_layer), Some(&Name::new("walls")));
+        assert_eq!(
+            line_styles.get(new_layer).unwrap().stroke.as_rgba_u32(),
+            Color::rgb8(0xff, 0, 0).as_rgba_u32()
+        );
+
+        let objects: Vec<_> = (&entities, &drawing_objects).join().collect();
+        assert_eq!(objects.len(), 2);
+        assert!(objects.iter().all(|(_, obj)| obj.layer == new_layer));
+        let (new_first, _) = objects
+            .iter()
+            .find(|(_, obj)| *obj.geometry() == Geometry::Line(line))
+            .unwrap();
+        let (new_second, _) =
+            objects.iter().find(|(ent, _)| ent != new_first).unwrap();
+        assert_eq!(names.get(*new_first), Some(&Name::new("first")));
+        assert_eq!(
+            groups.get(*new_second),
+            Some(&Group { parent: *new_first })
+        );
+        assert!(construction.contains(*new_second));
+        assert!(!construction.contains(*new_first));
+    }
+}
@@ -18,18 +18,29 @@
 //! [mco]: https://en.wikipedia.org/wiki/Mars_Climate_Orbiter
 
 #![forbid(unsafe_code)]
-#![deny(missing_debug_implementations, intra_doc_link_resolution_failure)]
+#![deny(missing_debug_implementations, rustdoc::broken_intra_doc_links)]
 
 pub mod components;
+pub mod events;
+pub mod io;
+pub mod render;
+pub mod snapping;
 pub mod systems;
+#[cfg(feature = "lyon")]
+pub mod tessellation;
 mod types;
 pub mod window;
 
 pub use arcs_core::*;
+pub use components::drawing_bounds;
 
 pub use types::{CanvasSpace, DrawingSpace, Length, Point, Transform, Vector};
 
 /// An [`primitives::Arc`] in [`DrawingSpace`].
 pub type Arc = primitives::Arc<DrawingSpace>;
+/// An [`primitives::Ellipse`] in [`DrawingSpace`].
+pub type Ellipse = primitives::Ellipse<DrawingSpace>;
+/// An [`primitives::EllipticalArc`] in [`DrawingSpace`].
+pub type EllipticalArc = primitives::EllipticalArc<DrawingSpace>;
 /// A [`primitives::Line`] in [`DrawingSpace`].
 pub type Line = primitives::Line<DrawingSpace>;
@@ -0,0 +1,71 @@
+use crate::CanvasSpace;
+use euclid::{Point2D, Vector2D};
+use kurbo::BezPath;
+use piet::{IntoBrush, RenderContext};
+
+/// Fill a triangular arrowhead with its tip at `tip`, pointing in the
+/// `direction` vector.
+///
+/// Everything is in [`CanvasSpace`], so `length` is in pixels and the
+/// arrowhead stays the same size regardless of the zoom level. This is used
+/// for dimension lines, but works just as well for leader lines or for
+/// visualising vectors.
+pub fn arrowhead<R: RenderContext>(
+    ctx: &mut R,
+    tip: Point2D<f64, CanvasSpace>,
+    direction: Vector2D<f64, CanvasSpace>,
+    length: f64,
+    brush: &impl IntoBrush<R>,
+) {
+    ctx.fill(arrowhead_path(tip, direction, length), brush);
+}
+
+/// The outline of an [`arrowhead()`].
+///
+/// The arrowhead is `length` long and two thirds as wide. A zero `direction`
+/// has no direction, so the arrowhead will point along the positive x-axis.
+pub fn arrowhead_path(
+    tip: Point2D<f64, CanvasSpace>,
+    direction: Vector2D<f64, CanvasSpace>,
+    length: f64,
+) -> BezPath {
+    let direction = if direction.length() > 0.0 {
+        direction.normalize()
+    } else {
+        Vector2D::new(1.0, 0.0)
+    };
+    let back = tip - direction * length;
+    let half_width = Vector2D::new(-direction.y, direction.x) * length / 3.0;
+
+    let mut path = BezPath::new();
+    path.move_to(tip.to_tuple());
+    path.line_to((back + half_width).to_tuple());
+    path.line_to((back - half_width).to_tuple());
+    path.close_path();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::{Rect, Shape};
+
+    #[test]
+    fn arrowhead_pointing_right() {
+        let tip = Point2D::new(10.0, 5.0);
+
+        let got = arrowhead_path(tip, Vector2D::new(2.0, 0.0), 6.0);
+
+        assert_eq!(got.bounding_box(), Rect::new(4.0, 3.0, 10.0, 7.0));
+    }
+
+    #[test]
+    fn zero_direction_points_along_the_x_axis() {
+        let tip = Point2D::new(0.0, 0.0);
+
+        let got = arrowhead_path(tip, Vector2D::zero(), 3.0);
+
+        assert_eq!(got.bounding_box(), Rect::new(-3.0, -1.0, 0.0, 1.0));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{components::Viewport, window::Window, CanvasSpace};
+use euclid::Size2D;
+use piet::ImageFormat;
+use piet_common::Device;
+use specs::prelude::*;
+
+/// An image in memory, stored as premultiplied RGBA pixels in row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageBuf {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl ImageBuf {
+    /// Get the RGBA value of the pixel at `(x, y)`, if it is inside the image.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let start = (y * self.width + x) * 4;
+        let mut rgba = [0; 4];
+        rgba.copy_from_slice(&self.pixels[start..start + 4]);
+
+        Some(rgba)
+    }
+}
+
+/// Render the drawing as seen through a [`Window`] and [`Viewport`] to an
+/// image.
+///
+/// This goes through the same [`System`] as [`Window::render_system()`],
+/// using the [`Window`]'s styling, so the image will match what would be shown
+/// on screen. The [`Window`]'s own [`Viewport`] is left untouched.
+pub fn to_image(
+    world: &World,
+    window: &Window,
+    viewport: &Viewport,
+    size: Size2D<f64, CanvasSpace>,
+) -> Result<ImageBuf, piet::Error> {
+    let width = size.width.ceil() as usize;
+    let height = size.height.ceil() as usize;
+
+    let mut device = Device::new()?;
+    let mut target = device.bitmap_target(width, height, 1.0)?;
+
+    {
+        let mut system = window.render_system_with_viewport(
+            target.render_context(),
+            size,
+            viewport.clone(),
+        );
+        RunNow::run_now(&mut system, world);
+    }
+
+    let pixels = target.into_raw_pixels(ImageFormat::RgbaPremul)?;
+
+    Ok(ImageBuf {
+        width,
+        height,
+        pixels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{
+            Dimension, DrawingObject, Geometry, Layer, LineStyle, Name,
+        },
+        Angle, Line, Point,
+    };
+    use euclid::Scale;
+    use piet::Color;
+
+    #[test]
+    fn render_a_line_to_an_image() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Line(Line::new(
+                    Point::new(-5.0, 0.0),
+                    Point::new(5.0, 0.0),
+                )),
+                layer,
+            ))
+            .with(LineStyle {
+                width: Dimension::Pixels(4.0),
+                stroke: Color::BLACK,
+                ..Default::default()
+            })
+            .build();
+        let window = Window::create(&mut world);
+        window
+            .style_mut(&mut world.write_storage())
+            .background_colour = Color::WHITE;
+        let viewport = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(1.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+
+        let got = to_image(&world, &window, &viewport, Size2D::new(20.0, 10.0))
+            .unwrap();
+
+        assert_eq!(got.width, 20);
+        assert_eq!(got.height, 10);
+        assert_eq!(got.pixels.len(), 20 * 10 * 4);
+        // the line runs horizontally through the middle of the image
+        assert_eq!(got.pixel(10, 5), Some([0, 0, 0, 0xff]));
+        assert_eq!(got.pixel(10, 0), Some([0xff, 0xff, 0xff, 0xff]));
+        assert_eq!(got.pixel(20, 0), None);
+    }
+}
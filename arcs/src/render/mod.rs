@@ -0,0 +1,11 @@
+//! Reusable drawing helpers, plus headless rendering (behind the `headless`
+//! feature) for generating thumbnails or snapshot tests without a browser or
+//! GUI.
+
+mod annotations;
+#[cfg(feature = "headless")]
+mod headless;
+
+pub use annotations::{arrowhead, arrowhead_path};
+#[cfg(feature = "headless")]
+pub use headless::{to_image, ImageBuf};
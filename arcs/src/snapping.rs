@@ -0,0 +1,68 @@
+//! Helpers for snapping the cursor to "interesting" locations in the drawing.
+
+use crate::{Length, Point};
+
+/// Round a [`Point`] to the nearest intersection on a regular grid.
+///
+/// The grid is made up of squares `spacing` drawing units wide, with one of
+/// the grid intersections located at `origin`.
+///
+/// A zero (or negative) `spacing` doesn't describe a usable grid, so the
+/// `point` is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use arcs::{snapping::snap_to_grid, Length, Point};
+///
+/// let got = snap_to_grid(Point::new(1.2, 3.9), Length::new(1.0), Point::zero());
+///
+/// assert_eq!(got, Point::new(1.0, 4.0));
+/// ```
+pub fn snap_to_grid(point: Point, spacing: Length, origin: Point) -> Point {
+    let spacing = spacing.get();
+
+    if spacing <= 0.0 || !spacing.is_finite() {
+        return point;
+    }
+
+    let relative = point - origin;
+    let snapped = (relative / spacing).round() * spacing;
+
+    origin + snapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_relative_to_a_different_origin() {
+        let origin = Point::new(0.5, -0.25);
+        let spacing = Length::new(2.0);
+
+        let got = snap_to_grid(Point::new(3.4, 1.0), spacing, origin);
+
+        assert_eq!(got, Point::new(2.5, 1.75));
+    }
+
+    #[test]
+    fn points_on_the_grid_stay_put() {
+        let point = Point::new(-15.0, 30.0);
+
+        let got = snap_to_grid(point, Length::new(5.0), Point::zero());
+
+        assert_eq!(got, point);
+    }
+
+    #[test]
+    fn invalid_spacing_leaves_the_point_unchanged() {
+        let point = Point::new(1.23, 4.56);
+
+        for &spacing in &[0.0, -1.0, f64::NAN] {
+            let got = snap_to_grid(point, Length::new(spacing), Point::zero());
+
+            assert_eq!(got, point);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{algorithms::Bounded, components::DrawingObject, BoundingBox, DrawingSpace};
+use crate::{components::DrawingObject, BoundingBox, DrawingSpace};
 use specs::prelude::*;
 
 /// Lets us keep track of a [`DrawingObject`]'s rough location in *Drawing
@@ -51,13 +51,103 @@ impl<'world> System<'world> for SyncBounds {
         for (ent, drawing_object, _) in
             (&entities, &drawing_objects, &self.to_update).join()
         {
-            bounds
-                .insert(ent, drawing_object.geometry.bounding_box())
-                .unwrap();
+            bounds.insert(ent, drawing_object.bounds()).unwrap();
         }
 
         for (ent, _) in (&entities, &self.removed).join() {
             bounds.remove(ent);
         }
     }
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as shred::DynamicSystemData>::setup(
+            &self.accessor(),
+            world,
+        );
+
+        // anything created before our reader was registered won't show up as
+        // an event, so do a full pass
+        let drawing_objects = world.read_storage::<DrawingObject>();
+        let mut bounds = world.write_storage::<BoundingBox<DrawingSpace>>();
+
+        for (ent, drawing_object) in
+            (&world.entities(), &drawing_objects).join()
+        {
+            bounds.insert(ent, drawing_object.bounds()).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{Geometry, Layer, Name},
+        Line, Point,
+    };
+
+    fn line_entity(world: &mut World, line: Line) -> Entity {
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Line(line), layer))
+            .build()
+    }
+
+    #[test]
+    fn setup_calculates_bounds_for_existing_objects() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(3.0, 5.0));
+        let ent = line_entity(&mut world, line);
+
+        let mut system = SyncBounds::new(&world);
+        System::setup(&mut system, &mut world);
+
+        let bounds = world.read_storage::<BoundingBox<DrawingSpace>>();
+        assert_eq!(
+            bounds.get(ent),
+            Some(&BoundingBox::new(line.start, line.end))
+        );
+    }
+
+    #[test]
+    fn only_update_changed_objects() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let mut system = SyncBounds::new(&world);
+        System::setup(&mut system, &mut world);
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(3.0, 5.0));
+        let ent = line_entity(&mut world, line);
+
+        system.run_now(&world);
+        assert_eq!(
+            world.read_storage::<BoundingBox<DrawingSpace>>().get(ent),
+            Some(&BoundingBox::new(line.start, line.end))
+        );
+
+        let moved = Line::new(Point::zero(), Point::new(10.0, 10.0));
+        world
+            .write_storage::<DrawingObject>()
+            .get_mut(ent)
+            .unwrap()
+            .set_geometry(Geometry::Line(moved));
+        system.run_now(&world);
+        assert_eq!(
+            world.read_storage::<BoundingBox<DrawingSpace>>().get(ent),
+            Some(&BoundingBox::new(moved.start, moved.end))
+        );
+
+        world.write_storage::<DrawingObject>().remove(ent);
+        system.run_now(&world);
+        assert!(world
+            .read_storage::<BoundingBox<DrawingSpace>>()
+            .get(ent)
+            .is_none());
+    }
 }
@@ -2,11 +2,13 @@
 
 mod bounds;
 mod name_table_bookkeeping;
-// mod spatial_relation;
+mod orphaned_styles;
+mod spatial_relation;
 
 pub use bounds::SyncBounds;
 pub use name_table_bookkeeping::NameTableBookkeeping;
-// pub use spatial_relation::SpatialRelation;
+pub use orphaned_styles::RemoveOrphanedStyles;
+pub use spatial_relation::SpatialRelation;
 
 use specs::{DispatcherBuilder, World};
 
@@ -22,4 +24,14 @@ pub fn register_background_tasks<'a, 'b>(
             &[],
         )
         .with(SyncBounds::new(world), SyncBounds::NAME, &[])
+        .with(
+            RemoveOrphanedStyles::new(world),
+            RemoveOrphanedStyles::NAME,
+            &[],
+        )
+        .with(
+            SpatialRelation::new(world),
+            SpatialRelation::NAME,
+            &[SyncBounds::NAME],
+        )
 }
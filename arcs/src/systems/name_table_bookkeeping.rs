@@ -55,6 +55,8 @@ impl<'world> System<'world> for NameTableBookkeeping {
                 Entry::Vacant(entry) => {
                     entry.insert(ent);
                 },
+                // already added (e.g. via NameTable::insert_unique())
+                Entry::Occupied(entry) if *entry.get() == ent => {},
                 Entry::Occupied(mut entry) => {
                     log::warn!(
                         "Duplicate name found when associating {:?} with \"{}\" (previous entity: {:?})",
@@ -130,4 +132,26 @@ mod tests {
         assert_eq!(names.get("second").unwrap(), second);
         assert_eq!(names.get("third").unwrap(), third);
     }
+
+    #[test]
+    fn names_inserted_up_front_are_not_duplicates() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let mut system = NameTableBookkeeping::new(&world);
+        System::setup(&mut system, &mut world);
+        let first = world.create_entity().with(Name::new("foo")).build();
+        system.run_now(&world);
+
+        let second = world.create_entity().build();
+        let name = world
+            .write_resource::<NameTable>()
+            .insert_unique("foo", second);
+        world.write_storage().insert(second, name).unwrap();
+        system.run_now(&world);
+
+        let names = world.read_resource::<NameTable>();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names.get("foo").unwrap(), first);
+        assert_eq!(names.get("foo_1").unwrap(), second);
+    }
 }
@@ -0,0 +1,162 @@
+use crate::components::{
+    DrawingObject, Hovered, LineStyle, PointStyle, Selected,
+};
+use specs::{prelude::*, world::Index};
+use std::collections::HashMap;
+
+/// A [`System`] which removes the [`LineStyle`], [`PointStyle`],
+/// [`Selected`], and [`Hovered`] components from an entity when its
+/// [`DrawingObject`] is removed, so they don't linger in their storages.
+#[derive(Debug)]
+pub struct RemoveOrphanedStyles {
+    changes: ReaderId<ComponentEvent>,
+    /// The [`Entity`] each [`DrawingObject`] was attached to.
+    ///
+    /// Events only contain an [`Index`], which may have been recycled by the
+    /// time we see a [`ComponentEvent::Removed`].
+    owners: HashMap<Index, Entity>,
+}
+
+impl RemoveOrphanedStyles {
+    pub const NAME: &'static str = module_path!();
+
+    pub fn new(world: &World) -> Self {
+        let changes = world.write_storage::<DrawingObject>().register_reader();
+        let owners =
+            (&world.entities(), &world.read_storage::<DrawingObject>())
+                .join()
+                .map(|(ent, _)| (ent.id(), ent))
+                .collect();
+
+        RemoveOrphanedStyles { changes, owners }
+    }
+}
+
+impl<'world> System<'world> for RemoveOrphanedStyles {
+    type SystemData = (
+        Entities<'world>,
+        ReadStorage<'world, DrawingObject>,
+        WriteStorage<'world, LineStyle>,
+        WriteStorage<'world, PointStyle>,
+        WriteStorage<'world, Selected>,
+        WriteStorage<'world, Hovered>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            drawing_objects,
+            mut line_styles,
+            mut point_styles,
+            mut selected,
+            mut hovered,
+        ) = data;
+
+        let mut orphans = Vec::new();
+
+        for event in drawing_objects.channel().read(&mut self.changes) {
+            match *event {
+                ComponentEvent::Inserted(id) => {
+                    self.owners.insert(id, entities.entity(id));
+                },
+                ComponentEvent::Removed(id) => {
+                    orphans.extend(self.owners.remove(&id));
+                },
+                ComponentEvent::Modified(_) => {},
+            }
+        }
+
+        for ent in orphans {
+            // if the entity was deleted its components went with it, and the
+            // index may now belong to something else. It may also have been
+            // given a new DrawingObject, in which case its styles are still
+            // wanted.
+            if !entities.is_alive(ent) || drawing_objects.contains(ent) {
+                continue;
+            }
+
+            line_styles.remove(ent);
+            point_styles.remove(ent);
+            selected.remove(ent);
+            hovered.remove(ent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{Geometry, Layer, Name},
+        Point,
+    };
+
+    #[test]
+    fn removing_a_drawing_object_removes_its_styles() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create_with_styles(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+            LineStyle::default(),
+            PointStyle::default(),
+        );
+        let mut system = RemoveOrphanedStyles::new(&world);
+        System::setup(&mut system, &mut world);
+        let styled = world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .with(LineStyle::default())
+            .with(PointStyle::default())
+            .with(Selected)
+            .with(Hovered)
+            .build();
+        system.run_now(&world);
+        assert!(world.read_storage::<LineStyle>().contains(styled));
+
+        world.write_storage::<DrawingObject>().remove(styled);
+        system.run_now(&world);
+
+        assert!(!world.read_storage::<LineStyle>().contains(styled));
+        assert!(!world.read_storage::<PointStyle>().contains(styled));
+        assert!(!world.read_storage::<Selected>().contains(styled));
+        assert!(!world.read_storage::<Hovered>().contains(styled));
+        // the layer's styles are left alone
+        assert!(world.read_storage::<LineStyle>().contains(layer));
+    }
+
+    #[test]
+    fn styles_on_a_recycled_entity_are_left_alone() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        let mut system = RemoveOrphanedStyles::new(&world);
+        System::setup(&mut system, &mut world);
+        let styled = world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .with(LineStyle::default())
+            .build();
+        system.run_now(&world);
+
+        world.delete_entity(styled).unwrap();
+        world.maintain();
+        let new_layer = Layer::create_with_styles(
+            world.create_entity(),
+            Name::new("new"),
+            Layer::default(),
+            LineStyle::default(),
+            PointStyle::default(),
+        );
+        assert_eq!(new_layer.id(), styled.id());
+        system.run_now(&world);
+
+        assert!(world.read_storage::<LineStyle>().contains(new_layer));
+        assert!(world.read_storage::<PointStyle>().contains(new_layer));
+    }
+}
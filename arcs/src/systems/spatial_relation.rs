@@ -1,4 +1,7 @@
-use crate::components::{BoundingBox, Space, SpatialEntity};
+use crate::{
+    components::{Space, SpatialEntity},
+    BoundingBox, DrawingSpace,
+};
 use specs::prelude::*;
 
 /// A [`System`] which keeps track of the spatial relation of entities
@@ -14,7 +17,9 @@ impl SpatialRelation {
 
     pub fn new(world: &World) -> Self {
         SpatialRelation {
-            changes: world.write_storage::<BoundingBox>().register_reader(),
+            changes: world
+                .write_storage::<BoundingBox<DrawingSpace>>()
+                .register_reader(),
             to_insert: BitSet::new(),
             to_update: BitSet::new(),
         }
@@ -24,7 +29,7 @@ impl SpatialRelation {
 impl<'world> System<'world> for SpatialRelation {
     type SystemData = (
         Write<'world, Space>,
-        ReadStorage<'world, BoundingBox>,
+        ReadStorage<'world, BoundingBox<DrawingSpace>>,
         Entities<'world>,
     );
 
@@ -69,7 +74,8 @@ impl<'world> System<'world> for SpatialRelation {
             world,
         );
 
-        let bounding_storage = world.read_storage::<BoundingBox>();
+        let bounding_storage =
+            world.read_storage::<BoundingBox<DrawingSpace>>();
         let mut space = world.write_resource::<Space>();
 
         space.clear();
@@ -117,13 +123,11 @@ mod tests {
         let line = Line::new(Point::new(2.0, 1.0), Point::new(5.0, -1.0));
         let first = world
             .create_entity()
-            .with(DrawingObject {
-                geometry: Geometry::Line(line),
-                layer,
-            })
+            .with(DrawingObject::new(Geometry::Line(line), layer))
             .with(LineStyle {
                 width: Dimension::DrawingUnits(Length::new(5.0)),
                 stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
             })
             .with(line.bounding_box())
             .build();
@@ -157,13 +161,11 @@ mod tests {
         let line = Line::new(Point::new(2.0, 1.0), Point::new(5.0, -1.0));
         let first = world
             .create_entity()
-            .with(DrawingObject {
-                geometry: Geometry::Line(line),
-                layer,
-            })
+            .with(DrawingObject::new(Geometry::Line(line), layer))
             .with(LineStyle {
                 width: Dimension::DrawingUnits(Length::new(5.0)),
                 stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
             })
             .with(line.bounding_box())
             .build();
@@ -176,13 +178,11 @@ mod tests {
         let line = Line::new(Point::new(3.0, 0.0), Point::new(-1.0, 2.0));
         let second = world
             .create_entity()
-            .with(DrawingObject {
-                geometry: Geometry::Line(line),
-                layer,
-            })
+            .with(DrawingObject::new(Geometry::Line(line), layer))
             .with(LineStyle {
                 width: Dimension::DrawingUnits(Length::new(5.0)),
                 stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
             })
             .with(line.bounding_box())
             .build();
@@ -193,7 +193,7 @@ mod tests {
         // query which is inside the bounding_box of first
         let query: Vec<_> = world
             .read_resource::<Space>()
-            .query_point(Point::new(3.0, -0.5), 1.0)
+            .query_point(Point::new(4.5, -0.5), Length::new(1.0))
             .collect();
         assert!(!query.is_empty());
         assert_eq!(query.len(), 1);
@@ -202,7 +202,7 @@ mod tests {
         // query which is inside bounding_box of both first and second
         let query: Vec<_> = world
             .read_resource::<Space>()
-            .query_point(Point::new(2.5, 0.5), 1.0)
+            .query_point(Point::new(2.5, 0.5), Length::new(1.0))
             .collect();
         assert!(!query.is_empty());
         assert_eq!(query.len(), 2);
@@ -232,13 +232,11 @@ mod tests {
         let line = Line::new(Point::new(2.0, 1.0), Point::new(5.0, -1.0));
         let first = world
             .create_entity()
-            .with(DrawingObject {
-                geometry: Geometry::Line(line),
-                layer,
-            })
+            .with(DrawingObject::new(Geometry::Line(line), layer))
             .with(LineStyle {
                 width: Dimension::DrawingUnits(Length::new(5.0)),
                 stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
             })
             .with(line.bounding_box())
             .build();
@@ -253,7 +251,7 @@ mod tests {
         // expected
         let query: Vec<_> = world
             .read_resource::<Space>()
-            .query_point(Point::new(3.0, -0.5), 1.0)
+            .query_point(Point::new(3.0, -0.5), Length::new(1.0))
             .collect();
         assert!(!query.is_empty());
         assert_eq!(query.len(), 1);
@@ -267,7 +265,7 @@ mod tests {
             .write_storage::<DrawingObject>()
             .get_mut(first)
             .unwrap()
-            .geometry = Geometry::Line(temp_line);
+            .set_geometry(Geometry::Line(temp_line));
 
         // run both systems
         syncbounds_system.run_now(&world);
@@ -276,7 +274,7 @@ mod tests {
         // do the same query again, this time we expect no results
         let query: Vec<_> = world
             .read_resource::<Space>()
-            .query_point(Point::new(3.0, -0.5), 1.0)
+            .query_point(Point::new(3.0, -0.5), Length::new(1.0))
             .collect();
         assert!(query.is_empty());
     }
@@ -301,13 +299,11 @@ mod tests {
         let line = Line::new(Point::new(2.0, 1.0), Point::new(5.0, -1.0));
         let first = world
             .create_entity()
-            .with(DrawingObject {
-                geometry: Geometry::Line(line),
-                layer,
-            })
+            .with(DrawingObject::new(Geometry::Line(line), layer))
             .with(LineStyle {
                 width: Dimension::DrawingUnits(Length::new(5.0)),
                 stroke: Color::rgb8(0xff, 0, 0),
+                ..Default::default()
             })
             .with(line.bounding_box())
             .build();
@@ -322,7 +318,7 @@ mod tests {
         // expected
         let query: Vec<_> = world
             .read_resource::<Space>()
-            .query_point(Point::new(3.0, -0.5), 1.0)
+            .query_point(Point::new(3.0, -0.5), Length::new(1.0))
             .collect();
         assert!(!query.is_empty());
         assert_eq!(query.len(), 1);
@@ -339,7 +335,7 @@ mod tests {
         // do the same query again, this time we expect no results
         let query: Vec<_> = world
             .read_resource::<Space>()
-            .query_point(Point::new(3.0, -0.5), 1.0)
+            .query_point(Point::new(3.0, -0.5), Length::new(1.0))
             .collect();
         assert!(query.is_empty());
     }
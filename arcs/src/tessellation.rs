@@ -0,0 +1,162 @@
+//! Turn [`Geometry`] into triangles using [`lyon`], for renderers which draw
+//! directly on the GPU instead of going through [`piet`].
+//!
+//! Vertices are in [`crate::DrawingSpace`] and it's up to the caller to
+//! transform them to the screen (e.g. in a vertex shader).
+
+use crate::{components::Geometry, Point};
+use lyon::{
+    math,
+    path::Path,
+    tessellation::{
+        BuffersBuilder, FillOptions, FillTessellator, FillVertex,
+        StrokeOptions, StrokeTessellator, StrokeVertex, TessellationError,
+        VertexBuffers,
+    },
+};
+
+/// Triangulated output from [`fill()`] or [`stroke()`].
+pub type Mesh = VertexBuffers<math::Point, u32>;
+
+/// Triangulate the area enclosed by some [`Geometry`].
+///
+/// Open shapes are closed with a straight line first, so an arc is filled as
+/// the segment between the arc and its chord. Points, lines, and dimensions
+/// don't enclose anything and give an empty [`Mesh`].
+pub fn fill(
+    geometry: &Geometry,
+    tolerance: f64,
+) -> Result<Mesh, TessellationError> {
+    let mut mesh = Mesh::new();
+    let path = match geometry {
+        Geometry::Arc(_) | Geometry::Ellipse(_) => {
+            path(&[geometry.to_polyline(tolerance)], true)
+        },
+        _ => return Ok(mesh),
+    };
+
+    FillTessellator::new().tessellate_path(
+        &path,
+        &FillOptions::tolerance(tolerance as f32),
+        &mut BuffersBuilder::new(&mut mesh, |v: FillVertex<'_>| v.position()),
+    )?;
+
+    Ok(mesh)
+}
+
+/// Triangulate the outline of some [`Geometry`], using a line `width`
+/// drawing units wide.
+///
+/// Points have no outline and give an empty [`Mesh`].
+pub fn stroke(
+    geometry: &Geometry,
+    width: f64,
+    tolerance: f64,
+) -> Result<Mesh, TessellationError> {
+    let mut mesh = Mesh::new();
+    let polylines = match *geometry {
+        Geometry::Point(_) => return Ok(mesh),
+        Geometry::Line(_) | Geometry::Arc(_) | Geometry::Ellipse(_) => {
+            vec![geometry.to_polyline(tolerance)]
+        },
+        Geometry::LinearDimension { from, to, offset } => {
+            let (first, second, dimension_line) =
+                Geometry::linear_dimension_lines(from, to, offset);
+
+            [first, second, dimension_line]
+                .iter()
+                .map(|line| vec![line.start, line.end])
+                .collect()
+        },
+    };
+
+    StrokeTessellator::new().tessellate_path(
+        &path(&polylines, false),
+        &StrokeOptions::tolerance(tolerance as f32)
+            .with_line_width(width as f32),
+        &mut BuffersBuilder::new(&mut mesh, |v: StrokeVertex<'_, '_>| {
+            v.position()
+        }),
+    )?;
+
+    Ok(mesh)
+}
+
+fn path(polylines: &[Vec<Point>], close: bool) -> Path {
+    let to_lyon = |p: &Point| math::point(p.x as f32, p.y as f32);
+    let mut builder = Path::builder();
+
+    for polyline in polylines {
+        let mut points = polyline.iter().map(to_lyon);
+
+        if let Some(first) = points.next() {
+            builder.begin(first);
+            for point in points {
+                builder.line_to(point);
+            }
+            builder.end(close);
+        }
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Angle, Arc, Line};
+
+    /// The total area covered by a [`Mesh`]'s triangles.
+    fn area(mesh: &Mesh) -> f64 {
+        mesh.indices
+            .chunks(3)
+            .map(|triangle| {
+                let a = mesh.vertices[triangle[0] as usize];
+                let b = mesh.vertices[triangle[1] as usize];
+                let c = mesh.vertices[triangle[2] as usize];
+                f64::from((b - a).cross(c - a)).abs() / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn fill_a_half_circle() {
+        let arc = Arc::from_centre_radius(
+            Point::zero(),
+            10.0,
+            Angle::zero(),
+            Angle::pi(),
+        );
+
+        let got = fill(&Geometry::Arc(arc), 0.01).unwrap();
+
+        let expected = std::f64::consts::PI * 10.0 * 10.0 / 2.0;
+        assert!((area(&got) - expected).abs() < 0.5, "{}", area(&got));
+    }
+
+    #[test]
+    fn lines_have_nothing_to_fill() {
+        let line = Line::new(Point::zero(), Point::new(10.0, 0.0));
+
+        let got = fill(&Geometry::Line(line), 0.01).unwrap();
+
+        assert!(got.vertices.is_empty());
+        assert!(got.indices.is_empty());
+    }
+
+    #[test]
+    fn stroke_a_line() {
+        let line = Line::new(Point::zero(), Point::new(10.0, 0.0));
+
+        let got = stroke(&Geometry::Line(line), 2.0, 0.01).unwrap();
+
+        assert!((area(&got) - 20.0).abs() < 1e-3, "{}", area(&got));
+    }
+
+    #[test]
+    fn points_have_no_outline() {
+        let got = stroke(&Geometry::Point(Point::zero()), 2.0, 0.01).unwrap();
+
+        assert!(got.vertices.is_empty());
+    }
+}
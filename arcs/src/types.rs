@@ -23,6 +23,9 @@ pub type Vector = Vector2D<f64, DrawingSpace>;
 /// A transform matrix which for translating something within [`DrawingSpace`].
 pub type Transform = euclid::Transform2D<f64, DrawingSpace, DrawingSpace>;
 /// A location in [`DrawingSpace`].
+///
+/// [`Point2D::distance_to()`] gives the distance between two points, and
+/// [`Point2D::lerp()`] with `t = 0.5` gives the point half-way between them.
 pub type Point = Point2D<f64, DrawingSpace>;
 /// A length in [`DrawingSpace`].
 pub type Length = euclid::Length<f64, DrawingSpace>;
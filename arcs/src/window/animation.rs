@@ -0,0 +1,220 @@
+use crate::{angle_between, components::Viewport};
+use euclid::Scale;
+use std::time::Duration;
+
+/// How an animation's progress changes over time.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum Easing {
+    /// Move at a constant speed.
+    Linear,
+    /// Start slowly and speed up.
+    EaseIn,
+    /// Start quickly and slow down at the end.
+    EaseOut,
+    /// Start slowly, speed up, then slow down at the end.
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map the fraction of time elapsed (`0.0` to `1.0`) to the fraction of
+    /// the distance travelled.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// Smoothly move from one [`Viewport`] to another (e.g. when zooming to fit
+/// the drawing) instead of jumping there instantly.
+///
+/// This doesn't use a timer, instead the caller passes in how much time has
+/// passed since the last frame.
+///
+/// ```rust
+/// # use arcs::{components::Viewport, window::ViewportAnimation, Angle, Point};
+/// # use euclid::Scale;
+/// # use std::time::Duration;
+/// let start = Viewport {
+///     centre: Point::zero(),
+///     pixels_per_drawing_unit: Scale::new(1.0),
+///     rotation: Angle::zero(),
+///     y_up: true,
+/// };
+/// let target = Viewport {
+///     centre: Point::new(100.0, 50.0),
+///     pixels_per_drawing_unit: Scale::new(4.0),
+///     ..start.clone()
+/// };
+/// let duration = Duration::from_millis(250);
+/// let mut animation = ViewportAnimation::new(start, target.clone(), duration);
+///
+/// while !animation.is_finished() {
+///     let viewport = animation.step(Duration::from_millis(16));
+///     // draw the frame using this viewport
+/// }
+///
+/// assert_eq!(animation.current(), target);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewportAnimation {
+    start: Viewport,
+    target: Viewport,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl ViewportAnimation {
+    /// Create a new [`ViewportAnimation`] which uses [`Easing::EaseInOut`].
+    pub fn new(start: Viewport, target: Viewport, duration: Duration) -> Self {
+        ViewportAnimation {
+            start,
+            target,
+            duration,
+            elapsed: Duration::default(),
+            easing: Easing::default(),
+        }
+    }
+
+    /// Use a different [`Easing`].
+    pub fn with_easing(self, easing: Easing) -> Self {
+        ViewportAnimation { easing, ..self }
+    }
+
+    /// The [`Viewport`] this animation will finish at.
+    pub fn target(&self) -> &Viewport { &self.target }
+
+    /// How far through the animation we are, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f64 {
+        if self.duration == Duration::default() {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+        }
+    }
+
+    /// Has the animation reached its target?
+    pub fn is_finished(&self) -> bool { self.progress() >= 1.0 }
+
+    /// Advance the animation by `dt` and get the [`Viewport`] to use for the
+    /// next frame.
+    pub fn step(&mut self, dt: Duration) -> Viewport {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        self.current()
+    }
+
+    /// The [`Viewport`] at the current point in the animation.
+    ///
+    /// The zoom level is interpolated logarithmically so zooming feels like
+    /// it happens at a constant speed, and rotation takes the shortest way
+    /// around.
+    pub fn current(&self) -> Viewport {
+        if self.is_finished() {
+            return self.target.clone();
+        }
+
+        let t = self.easing.apply(self.progress());
+        let start_scale = self.start.pixels_per_drawing_unit.get();
+        let target_scale = self.target.pixels_per_drawing_unit.get();
+        let scale = (start_scale.ln()
+            + (target_scale.ln() - start_scale.ln()) * t)
+            .exp();
+        let rotation = self.start.rotation
+            + angle_between(self.start.rotation, self.target.rotation) * t;
+
+        Viewport {
+            centre: self.start.centre.lerp(self.target.centre, t),
+            pixels_per_drawing_unit: Scale::new(scale),
+            rotation,
+            y_up: self.target.y_up,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Angle, Point};
+    use euclid::approxeq::ApproxEq;
+
+    fn viewports() -> (Viewport, Viewport) {
+        let start = Viewport {
+            centre: Point::zero(),
+            pixels_per_drawing_unit: Scale::new(1.0),
+            rotation: Angle::zero(),
+            y_up: true,
+        };
+        let target = Viewport {
+            centre: Point::new(100.0, -50.0),
+            pixels_per_drawing_unit: Scale::new(100.0),
+            rotation: Angle::degrees(-90.0),
+            y_up: true,
+        };
+
+        (start, target)
+    }
+
+    #[test]
+    fn animate_linearly_to_the_target() {
+        let (start, target) = viewports();
+        let mut animation = ViewportAnimation::new(
+            start.clone(),
+            target.clone(),
+            Duration::from_secs(1),
+        )
+        .with_easing(Easing::Linear);
+
+        assert_eq!(animation.current(), start);
+
+        let halfway = animation.step(Duration::from_millis(500));
+
+        assert!(halfway.centre.approx_eq(&Point::new(50.0, -25.0)));
+        // zoom is interpolated logarithmically, 1 -> 10 -> 100
+        assert!(halfway.pixels_per_drawing_unit.get().approx_eq(&10.0));
+        assert!(halfway.rotation.approx_eq(&Angle::degrees(-45.0)));
+        assert!(!animation.is_finished());
+
+        let end = animation.step(Duration::from_secs(10));
+
+        assert!(animation.is_finished());
+        assert_eq!(animation.progress(), 1.0);
+        assert_eq!(end, target);
+    }
+
+    #[test]
+    fn a_zero_duration_animation_finishes_immediately() {
+        let (start, target) = viewports();
+        let animation =
+            ViewportAnimation::new(start, target.clone(), Duration::default());
+
+        assert!(animation.is_finished());
+        assert_eq!(animation.current(), target);
+    }
+
+    #[test]
+    fn easing_functions_start_and_end_in_the_right_place() {
+        for &easing in &[
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0, "{:?}", easing);
+            assert_eq!(easing.apply(1.0), 1.0, "{:?}", easing);
+
+            let mut previous = 0.0;
+            for i in 1..=10 {
+                let got = easing.apply(i as f64 / 10.0);
+                assert!(got >= previous, "{:?} went backwards", easing);
+                previous = got;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use piet::Color;
+
+/// A resource which tells the renderer to draw extra diagnostic information
+/// over the top of the drawing.
+///
+/// This is useful when figuring out why an object isn't showing up (e.g. its
+/// cached [`crate::BoundingBox`] is out of date, so it gets culled). Insert
+/// it into the [`specs::World`] to turn the overlay on and remove it again
+/// to turn it off.
+///
+/// ```rust
+/// # use arcs::window::DebugOverlay;
+/// # use specs::prelude::*;
+/// let mut world = World::new();
+/// world.insert(DebugOverlay {
+///     show_quadtree: false,
+///     ..Default::default()
+/// });
+///
+/// // ... render a frame ...
+///
+/// world.remove::<DebugOverlay>();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DebugOverlay {
+    /// Stroke the cached [`crate::BoundingBox`] of each object which was
+    /// considered for drawing.
+    pub show_bounding_boxes: bool,
+    /// The colour used when drawing bounding boxes.
+    pub bounding_box_colour: Color,
+    /// Draw the boundaries of each node in the
+    /// [`crate::components::Space`]'s quadtree.
+    pub show_quadtree: bool,
+    /// The colour used when drawing quadtree nodes.
+    pub quadtree_colour: Color,
+}
+
+impl Default for DebugOverlay {
+    fn default() -> DebugOverlay {
+        DebugOverlay {
+            show_bounding_boxes: true,
+            bounding_box_colour: Color::rgb8(0xff, 0x00, 0xff),
+            show_quadtree: true,
+            quadtree_colour: Color::rgb8(0x00, 0xc0, 0x00),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{BoundingBox, DrawingSpace};
+
+/// A resource tracking which parts of the drawing have changed since the last
+/// frame.
+///
+/// Anything which modifies the drawing (e.g. moving a temporary point around
+/// while the mouse moves) can [`DirtyRegions::mark()`] the old and new
+/// locations as dirty. A renderer created with
+/// [`crate::window::Window::incremental_render_system()`] then only needs to
+/// repaint those areas.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DirtyRegions {
+    regions: Vec<BoundingBox<DrawingSpace>>,
+}
+
+impl DirtyRegions {
+    /// Flag an area of the drawing as needing to be redrawn.
+    pub fn mark(&mut self, bounds: BoundingBox<DrawingSpace>) {
+        self.regions.push(bounds);
+    }
+
+    /// Has anything been marked as dirty?
+    pub fn is_empty(&self) -> bool { self.regions.is_empty() }
+
+    /// The smallest [`BoundingBox`] containing every dirty region.
+    pub fn bounds(&self) -> Option<BoundingBox<DrawingSpace>> {
+        BoundingBox::around(&self.regions)
+    }
+
+    /// Forget about all dirty regions, normally called after they have been
+    /// redrawn.
+    pub fn clear(&mut self) { self.regions.clear(); }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    #[test]
+    fn bounds_cover_every_dirty_region() {
+        let mut dirty = DirtyRegions::default();
+        assert!(dirty.is_empty());
+        assert_eq!(dirty.bounds(), None);
+
+        dirty
+            .mark(BoundingBox::new(Point::new(1.0, 1.0), Point::new(2.0, 2.0)));
+        dirty.mark(BoundingBox::new(
+            Point::new(-5.0, 0.0),
+            Point::new(-4.0, 1.5),
+        ));
+
+        assert_eq!(
+            dirty.bounds(),
+            Some(BoundingBox::new(
+                Point::new(-5.0, 0.0),
+                Point::new(2.0, 2.0)
+            ))
+        );
+
+        dirty.clear();
+        assert!(dirty.is_empty());
+    }
+}
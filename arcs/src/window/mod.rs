@@ -1,10 +1,17 @@
 //! Rendering and window management for the `arcs` CAD library.
 
+mod animation;
+mod debug_overlay;
+mod dirty_regions;
 mod utils;
+#[allow(clippy::module_inception)]
 mod window;
 
+pub use animation::{Easing, ViewportAnimation};
+pub use debug_overlay::DebugOverlay;
+pub use dirty_regions::DirtyRegions;
 pub use utils::{
     to_canvas_coordinates, to_drawing_coordinates, transform_to_canvas_space,
     transform_to_drawing_space,
 };
-pub use window::Window;
+pub use window::{RenderStats, Window};
@@ -1,5 +1,5 @@
 use crate::{components::Viewport, CanvasSpace, DrawingSpace};
-use euclid::{Point2D, Size2D, Transform2D, Vector2D};
+use euclid::{Point2D, Rotation2D, Size2D, Transform2D, Vector2D};
 
 pub fn to_canvas_coordinates(
     point: Point2D<f64, DrawingSpace>,
@@ -26,15 +26,24 @@ pub fn transform_to_drawing_space(
 
     let drawing_units_per_pixel = viewport.pixels_per_drawing_unit.inv();
 
+    let rotation: Rotation2D<f64, DrawingSpace, DrawingSpace> =
+        Rotation2D::new(viewport.rotation);
+
     // calculate the new basis vectors
     let x_axis = Vector2D::new(1.0, 0.0);
-    let x_axis_basis = drawing_units_per_pixel.transform_vector(x_axis);
-    let y_axis = Vector2D::new(0.0, -1.0);
-    let y_axis_basis = drawing_units_per_pixel.transform_vector(y_axis);
+    let x_axis_basis = rotation
+        .transform_vector(drawing_units_per_pixel.transform_vector(x_axis));
+    let y_axis = Vector2D::new(0.0, viewport.y_axis_direction());
+    let y_axis_basis = rotation
+        .transform_vector(drawing_units_per_pixel.transform_vector(y_axis));
     // and where our origin will now be
     let new_origin = Vector2D::new(viewport.centre.x, viewport.centre.y)
-        + Vector2D::new(-window.width / 2.0, window.height / 2.0)
-            * drawing_units_per_pixel;
+        + rotation.transform_vector(
+            Vector2D::new(
+                -window.width / 2.0,
+                -viewport.y_axis_direction() * window.height / 2.0,
+            ) * drawing_units_per_pixel,
+        );
 
     // This gives us a column-order matrix (x * T => x'):
     //   | x_basis.x  x_basis.y  0 |
@@ -59,15 +68,14 @@ pub fn to_drawing_coordinates(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use euclid::Scale;
+    use crate::Angle;
+    use euclid::{approxeq::ApproxEq, Scale};
+
+    type Vertex = (Point2D<f64, DrawingSpace>, Point2D<f64, CanvasSpace>);
 
     /// These are the numbers from an example I drew out on paper and calculated
     /// by hand.
-    fn known_example() -> (
-        Vec<(Point2D<f64, DrawingSpace>, Point2D<f64, CanvasSpace>)>,
-        Viewport,
-        Size2D<f64, CanvasSpace>,
-    ) {
+    fn known_example() -> (Vec<Vertex>, Viewport, Size2D<f64, CanvasSpace>) {
         let vertices = vec![
             // viewport centre
             (Point2D::new(300.0, 150.0), Point2D::new(400.0, 200.0)),
@@ -83,6 +91,8 @@ mod tests {
         let viewport = Viewport {
             centre: Point2D::new(300.0, 150.0),
             pixels_per_drawing_unit: Scale::new(4.0),
+            rotation: Angle::zero(),
+            y_up: true,
         };
         let window = Size2D::new(800.0, 400.0);
 
@@ -126,4 +136,68 @@ mod tests {
             [4.0, 0.0, 0.0, -4.0, -800.0, 800.0]
         );
     }
+
+    #[test]
+    fn rotated_viewport() {
+        // the canvas's x-axis points up the drawing's y-axis
+        let viewport = Viewport {
+            centre: Point2D::new(300.0, 150.0),
+            pixels_per_drawing_unit: Scale::new(4.0),
+            rotation: Angle::frac_pi_2(),
+            y_up: true,
+        };
+        let window = Size2D::new(800.0, 400.0);
+        let vertices: Vec<Vertex> = vec![
+            // viewport centre
+            (Point2D::new(300.0, 150.0), Point2D::new(400.0, 200.0)),
+            // top-left
+            (Point2D::new(250.0, 50.0), Point2D::new(0.0, 0.0)),
+            // bottom-left
+            (Point2D::new(350.0, 50.0), Point2D::new(0.0, 400.0)),
+            // bottom-right
+            (Point2D::new(350.0, 250.0), Point2D::new(800.0, 400.0)),
+            // top-right
+            (Point2D::new(250.0, 250.0), Point2D::new(800.0, 0.0)),
+        ];
+
+        for (drawing_space, canvas_space) in vertices {
+            let got = to_canvas_coordinates(drawing_space, &viewport, window);
+            assert!(
+                got.approx_eq(&canvas_space),
+                "{:?} != {:?}",
+                got,
+                canvas_space
+            );
+
+            let got = to_drawing_coordinates(canvas_space, &viewport, window);
+            assert!(
+                got.approx_eq(&drawing_space),
+                "{:?} != {:?}",
+                got,
+                drawing_space
+            );
+        }
+    }
+
+    #[test]
+    fn viewport_without_the_y_flip() {
+        let (_, mut viewport, window) = known_example();
+        viewport.y_up = false;
+        let vertices: Vec<Vertex> = vec![
+            // viewport centre
+            (Point2D::new(300.0, 150.0), Point2D::new(400.0, 200.0)),
+            // top-left
+            (Point2D::new(200.0, 100.0), Point2D::new(0.0, 0.0)),
+            // bottom-right
+            (Point2D::new(400.0, 200.0), Point2D::new(800.0, 400.0)),
+        ];
+
+        for (drawing_space, canvas_space) in vertices {
+            let got = to_canvas_coordinates(drawing_space, &viewport, window);
+            assert_eq!(got, canvas_space);
+
+            let got = to_drawing_coordinates(canvas_space, &viewport, window);
+            assert_eq!(got, drawing_space);
+        }
+    }
 }
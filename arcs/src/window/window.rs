@@ -1,14 +1,17 @@
 use crate::{
-    algorithms::Bounded,
     components::{
-        DrawingObject, Geometry, Layer, LineStyle, PointStyle, Viewport,
-        WindowStyle,
+        Construction, DrawPriority, DrawingObject, Geometry, Hovered, Layer,
+        LineStyle, PointStyle, Space, Viewport, WindowStyle,
     },
-    BoundingBox, CanvasSpace, DrawingSpace, Line, Point,
+    render::arrowhead,
+    window::{DebugOverlay, DirtyRegions},
+    Angle, BoundingBox, CanvasSpace, DrawingSpace, Length, Line, Point,
 };
 use euclid::{Point2D, Scale, Size2D};
-use kurbo::Circle;
-use piet::RenderContext;
+use kurbo::{BezPath, Circle};
+use piet::{
+    Color, FontBuilder, RenderContext, Text, TextLayout, TextLayoutBuilder,
+};
 use shred_derive::SystemData;
 use specs::{join::MaybeJoin, prelude::*};
 use std::{cmp::Reverse, collections::BTreeMap};
@@ -25,6 +28,8 @@ impl Window {
             .with(Viewport {
                 centre: Point::zero(),
                 pixels_per_drawing_unit: Scale::new(1.0),
+                rotation: Angle::zero(),
+                y_up: true,
             })
             .with(LineStyle::default())
             .with(PointStyle::default())
@@ -53,6 +58,70 @@ impl Window {
             backend,
             window_size,
             window: self,
+            viewport: None,
+            draw_calls: 0,
+            incremental: false,
+        }
+    }
+
+    /// Get a rendering [`System`] which only repaints the parts of the canvas
+    /// flagged in the [`DirtyRegions`] resource.
+    ///
+    /// Everything gets redrawn if there is no [`DirtyRegions`] resource, or
+    /// the dirty area covers most of the window. Nothing is drawn if there
+    /// aren't any dirty regions, so the canvas must already contain the
+    /// previous frame.
+    pub fn incremental_render_system<'a, R>(
+        &'a self,
+        backend: R,
+        window_size: Size2D<f64, CanvasSpace>,
+    ) -> impl System<'a> + 'a
+    where
+        R: RenderContext + 'a,
+    {
+        RenderSystem {
+            backend,
+            window_size,
+            window: self,
+            viewport: None,
+            draw_calls: 0,
+            incremental: true,
+        }
+    }
+
+    /// Get a rendering [`System`] which shows the drawing through a particular
+    /// [`Viewport`] instead of the window's own.
+    #[cfg(feature = "headless")]
+    pub(crate) fn render_system_with_viewport<'a, R>(
+        &'a self,
+        backend: R,
+        window_size: Size2D<f64, CanvasSpace>,
+        viewport: Viewport,
+    ) -> impl System<'a> + 'a
+    where
+        R: RenderContext + 'a,
+    {
+        RenderSystem {
+            backend,
+            window_size,
+            window: self,
+            viewport: Some(viewport),
+            draw_calls: 0,
+            incremental: false,
+        }
+    }
+
+    /// Update the [`Viewport`] so the entire drawing is visible.
+    ///
+    /// The [`Viewport`] is left as-is when there is nothing to draw.
+    pub fn fit_to_drawing(
+        &self,
+        world: &World,
+        window_size: Size2D<f64, CanvasSpace>,
+    ) {
+        if let Some(bounds) = crate::drawing_bounds(world) {
+            self.viewport_mut(&mut world.write_storage())
+                .fit_to_bounds(bounds, window_size);
         }
     }
 }
@@ -102,17 +171,137 @@ struct RenderSystem<'window, B> {
     backend: B,
     window_size: Size2D<f64, CanvasSpace>,
     window: &'window Window,
+    /// Overrides the [`Viewport`] attached to the [`Window`].
+    viewport: Option<Viewport>,
+    /// How many times the [`RenderContext`] has been asked to draw something
+    /// this frame.
+    draw_calls: usize,
+    /// Should we only repaint the [`DirtyRegions`]?
+    incremental: bool,
+}
+
+/// Extra space (in pixels) around each dirty region, so strokes and points
+/// which stick out past their bounds get repainted too.
+const DIRTY_REGION_MARGIN: f64 = 10.0;
+
+/// How much of the canvas needs to be repainted.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Redraw {
+    Nothing,
+    Everything,
+    /// Just this part of the canvas.
+    Region(kurbo::Rect),
 }
 
 impl<'window, B> RenderSystem<'window, B> {
     /// Calculate the area of the drawing displayed by the viewport.
-    fn viewport_dimensions(&self, viewport: &Viewport) -> BoundingBox<DrawingSpace> {
-        let window_size = viewport
-            .pixels_per_drawing_unit
-            .inv()
-            .transform_size(self.window_size);
+    fn viewport_dimensions(
+        &self,
+        viewport: &Viewport,
+    ) -> BoundingBox<DrawingSpace> {
+        // the viewport may be rotated, so find the area covered by the
+        // window's corners
+        let to_drawing_space =
+            super::transform_to_drawing_space(viewport, self.window_size);
+        let Size2D { width, height, .. } = self.window_size;
+        let corners = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(width, 0.0),
+            Point2D::new(width, height),
+            Point2D::new(0.0, height),
+        ];
+
+        BoundingBox::around(
+            corners
+                .iter()
+                .map(|&corner| to_drawing_space.transform_point(corner)),
+        )
+        .expect("There are always 4 corners")
+    }
+
+    /// Figure out how much of the canvas needs to be repainted, based on the
+    /// [`DirtyRegions`].
+    fn redraw(
+        &self,
+        viewport: &Viewport,
+        dirty_regions: Option<&DirtyRegions>,
+    ) -> Redraw {
+        /// Repaint everything when the dirty area covers more than this
+        /// fraction of the window.
+        const MAX_DIRTY_FRACTION: f64 = 0.6;
+
+        let dirty_bounds = match dirty_regions {
+            Some(dirty_regions) if self.incremental => dirty_regions.bounds(),
+            _ => return Redraw::Everything,
+        };
+        let dirty_bounds = match dirty_bounds {
+            Some(bounds) => bounds,
+            None => return Redraw::Nothing,
+        };
+
+        let to_canvas =
+            super::transform_to_canvas_space(viewport, self.window_size);
+        let corners = [
+            dirty_bounds.bottom_left(),
+            dirty_bounds.bottom_right(),
+            dirty_bounds.top_right(),
+            dirty_bounds.top_left(),
+        ];
+        let canvas_bounds = BoundingBox::around(
+            corners
+                .iter()
+                .map(|&corner| to_canvas.transform_point(corner)),
+        )
+        .expect("There are always 4 corners");
+        let region = kurbo::Rect::new(
+            canvas_bounds.min_x(),
+            canvas_bounds.min_y(),
+            canvas_bounds.max_x(),
+            canvas_bounds.max_y(),
+        )
+        .inflate(DIRTY_REGION_MARGIN, DIRTY_REGION_MARGIN);
+        let window = kurbo::Rect::new(
+            0.0,
+            0.0,
+            self.window_size.width,
+            self.window_size.height,
+        );
+        let region = region.intersect(window);
+
+        if region.area() <= 0.0 {
+            // the changes are all off-screen
+            Redraw::Nothing
+        } else if region.area() > window.area() * MAX_DIRTY_FRACTION {
+            Redraw::Everything
+        } else {
+            Redraw::Region(region)
+        }
+    }
+
+    /// The area of the drawing which needs to be checked when repainting part
+    /// of the canvas.
+    fn drawing_area(
+        &self,
+        viewport: &Viewport,
+        region: kurbo::Rect,
+    ) -> BoundingBox<DrawingSpace> {
+        // anything close enough to bleed into the region gets drawn too
+        let region = region.inflate(DIRTY_REGION_MARGIN, DIRTY_REGION_MARGIN);
+        let to_drawing_space =
+            super::transform_to_drawing_space(viewport, self.window_size);
+        let corners = [
+            Point2D::new(region.x0, region.y0),
+            Point2D::new(region.x1, region.y0),
+            Point2D::new(region.x1, region.y1),
+            Point2D::new(region.x0, region.y1),
+        ];
 
-        BoundingBox::from_centre_and_size(viewport.centre, window_size)
+        BoundingBox::around(
+            corners
+                .iter()
+                .map(|&corner| to_drawing_space.transform_point(corner)),
+        )
+        .expect("There are always 4 corners")
     }
 }
 
@@ -124,7 +313,7 @@ impl<'window, B: RenderContext> RenderSystem<'window, B> {
         styles: &Styling,
         viewport: &Viewport,
     ) {
-        match drawing_object.geometry {
+        match *drawing_object.geometry() {
             Geometry::Point(point) => {
                 self.render_point(
                     ent,
@@ -134,19 +323,133 @@ impl<'window, B: RenderContext> RenderSystem<'window, B> {
                     viewport,
                 );
             },
-            Geometry::Line(ref line) => {
-                self.render_line(
+            Geometry::Line(_) | Geometry::Arc(_) | Geometry::Ellipse(_) => {
+                self.render_curve(
+                    ent,
+                    drawing_object.geometry(),
+                    drawing_object.layer,
+                    styles,
+                    viewport,
+                );
+            },
+            Geometry::LinearDimension { from, to, offset } => {
+                self.render_linear_dimension(
                     ent,
-                    line,
+                    (from, to, offset),
                     drawing_object.layer,
                     styles,
                     viewport,
                 );
             },
-            _ => unimplemented!(),
         }
     }
 
+    /// Draw the x and y axes through the origin, if they're visible.
+    fn render_axes(
+        &mut self,
+        window_style: &WindowStyle,
+        viewport: &Viewport,
+        viewport_dimensions: BoundingBox<DrawingSpace>,
+    ) {
+        let (min_x, max_x) =
+            (viewport_dimensions.min_x(), viewport_dimensions.max_x());
+        let (min_y, max_y) =
+            (viewport_dimensions.min_y(), viewport_dimensions.max_y());
+        let mut axes = Vec::new();
+
+        if min_y <= 0.0 && 0.0 <= max_y {
+            axes.push(Line::new(
+                Point::new(min_x, 0.0),
+                Point::new(max_x, 0.0),
+            ));
+        }
+        if min_x <= 0.0 && 0.0 <= max_x {
+            axes.push(Line::new(
+                Point::new(0.0, min_y),
+                Point::new(0.0, max_y),
+            ));
+        }
+
+        for axis in axes {
+            let start = self.to_canvas_coordinates(axis.start, viewport);
+            let end = self.to_canvas_coordinates(axis.end, viewport);
+            let shape = kurbo::Line::new(start.to_tuple(), end.to_tuple());
+            log::trace!("Drawing axis {:?} as {:?}", axis, shape);
+
+            self.backend.stroke(shape, &window_style.axes_colour, 1.0);
+            self.draw_calls += 1;
+        }
+    }
+
+    /// Draw the requested parts of the [`DebugOverlay`].
+    fn render_debug_overlay(
+        &mut self,
+        overlay: &DebugOverlay,
+        draw_order: &DrawOrder,
+        candidates: &BitSet,
+        viewport: &Viewport,
+        viewport_dimensions: BoundingBox<DrawingSpace>,
+    ) {
+        if overlay.show_quadtree {
+            if let Some(ref space) = draw_order.space {
+                for rect in space.node_rects() {
+                    if rect.intersects_with(viewport_dimensions) {
+                        self.stroke_bounds(
+                            rect,
+                            &overlay.quadtree_colour,
+                            viewport,
+                        );
+                    }
+                }
+            }
+        }
+
+        if overlay.show_bounding_boxes {
+            for (&bounds, _) in (&draw_order.bounding_boxes, candidates).join()
+            {
+                if bounds.intersects_with(viewport_dimensions) {
+                    self.stroke_bounds(
+                        bounds,
+                        &overlay.bounding_box_colour,
+                        viewport,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Stroke the outline of a [`BoundingBox`] with a 1 pixel line.
+    fn stroke_bounds(
+        &mut self,
+        bounds: BoundingBox<DrawingSpace>,
+        colour: &Color,
+        viewport: &Viewport,
+    ) {
+        // the viewport may be rotated, so transform each corner instead of
+        // assuming the box stays axis-aligned
+        let corners = [
+            bounds.bottom_left(),
+            bounds.bottom_right(),
+            bounds.top_right(),
+            bounds.top_left(),
+        ];
+        let mut path = BezPath::new();
+
+        for (i, &corner) in corners.iter().enumerate() {
+            let corner = self.to_canvas_coordinates(corner, viewport);
+
+            if i == 0 {
+                path.move_to(corner.to_tuple());
+            } else {
+                path.line_to(corner.to_tuple());
+            }
+        }
+        path.close_path();
+
+        self.backend.stroke(path, colour, 1.0);
+        self.draw_calls += 1;
+    }
+
     /// Draw a [`Point`] as a circle on the canvas.
     fn render_point(
         &mut self,
@@ -161,31 +464,158 @@ impl<'window, B: RenderContext> RenderSystem<'window, B> {
         let centre = self.to_canvas_coordinates(point, viewport);
         let shape = Circle {
             center: kurbo::Point::new(centre.x, centre.y),
-            radius: style.radius.in_pixels(viewport.pixels_per_drawing_unit),
+            radius: style.radius.in_pixels_for_window(
+                viewport.pixels_per_drawing_unit,
+                self.window_size,
+            ),
         };
+        let colour = resolve_colour(styles, self.window, entity, &style.colour);
         log::trace!("Drawing {:?} as {:?} using {:?}", point, shape, style);
 
-        self.backend.fill(shape, &style.colour);
+        self.backend.fill(shape, &colour);
+        self.draw_calls += 1;
+    }
+
+    /// Stroke a [`Geometry::Line`], [`Geometry::Arc`], or [`Geometry::Ellipse`]
+    /// using its [`LineStyle`], dashed if it is [`Construction`] geometry.
+    fn render_curve(
+        &mut self,
+        entity: Entity,
+        geometry: &Geometry,
+        layer: Entity,
+        styles: &Styling,
+        viewport: &Viewport,
+    ) {
+        let style = resolve_line_style(styles, self.window, entity, layer);
+
+        let transform =
+            super::transform_to_canvas_space(viewport, self.window_size);
+        let tolerance =
+            self.window.style(&styles.window_styles).curve_tolerance;
+        let shape = geometry.to_bez_path(&transform, tolerance);
+        let stroke_width = style.width.in_pixels_for_window(
+            viewport.pixels_per_drawing_unit,
+            self.window_size,
+        );
+        let colour = resolve_colour(styles, self.window, entity, &style.stroke);
+        log::trace!("Drawing {:?} as {:?} using {:?}", geometry, shape, style);
+
+        if styles.construction.contains(entity) {
+            /// The dash pattern used for construction geometry, in pixels.
+            const DASHES: [f64; 2] = [8.0, 4.0];
+
+            let mut stroke_style = style.stroke_style();
+            stroke_style.set_dash(DASHES.to_vec(), 0.0);
+            self.backend.stroke_styled(
+                shape,
+                &colour,
+                stroke_width,
+                &stroke_style,
+            );
+        } else {
+            self.backend.stroke_styled(
+                shape,
+                &colour,
+                stroke_width,
+                &style.stroke_style(),
+            );
+        }
+        self.draw_calls += 1;
     }
 
-    fn render_line(
+    /// Draw a [`Geometry::LinearDimension`] as a pair of extension lines, a
+    /// dimension line with arrowheads, and the measured length as text.
+    fn render_linear_dimension(
         &mut self,
         entity: Entity,
-        line: &Line,
+        (from, to, offset): (Point, Point, Length),
         layer: Entity,
         styles: &Styling,
         viewport: &Viewport,
     ) {
+        /// How long each arrowhead is, in pixels.
+        const ARROWHEAD_LENGTH: f64 = 10.0;
+        /// The label's font size, in pixels.
+        const FONT_SIZE: f64 = 12.0;
+
         let style = resolve_line_style(styles, self.window, entity, layer);
+        let stroke_width = style.width.in_pixels_for_window(
+            viewport.pixels_per_drawing_unit,
+            self.window_size,
+        );
+
+        let colour = resolve_colour(styles, self.window, entity, &style.stroke);
+
+        let (_, _, dimension_line) =
+            Geometry::linear_dimension_lines(from, to, offset);
+        log::trace!(
+            "Drawing a linear dimension from {:?} to {:?} using {:?}",
+            from,
+            to,
+            style
+        );
+
+        let transform =
+            super::transform_to_canvas_space(viewport, self.window_size);
+        let geometry = Geometry::LinearDimension { from, to, offset };
+        self.backend.stroke_styled(
+            geometry.to_bez_path(
+                &transform,
+                self.window.style(&styles.window_styles).curve_tolerance,
+            ),
+            &colour,
+            stroke_width,
+            &style.stroke_style(),
+        );
+
+        let start = self.to_canvas_coordinates(dimension_line.start, viewport);
+        let end = self.to_canvas_coordinates(dimension_line.end, viewport);
+        arrowhead(
+            &mut self.backend,
+            start,
+            start - end,
+            ARROWHEAD_LENGTH,
+            &colour,
+        );
+        arrowhead(
+            &mut self.backend,
+            end,
+            end - start,
+            ARROWHEAD_LENGTH,
+            &colour,
+        );
+        self.draw_calls += 3;
 
-        let start = self.to_canvas_coordinates(line.start, viewport);
-        let end = self.to_canvas_coordinates(line.end, viewport);
-        let shape = kurbo::Line::new(start.to_tuple(), end.to_tuple());
-        let stroke_width =
-            style.width.in_pixels(viewport.pixels_per_drawing_unit);
-        log::trace!("Drawing {:?} as {:?} using {:?}", line, shape, style);
+        let drawing_unit =
+            self.window.style(&styles.window_styles).drawing_unit;
+        let label = drawing_unit
+            .display(Length::new(from.distance_to(to)))
+            .to_string();
+        let midpoint = start.lerp(end, 0.5);
 
-        self.backend.stroke(shape, &style.stroke, stroke_width);
+        let layout = self
+            .backend
+            .text()
+            .new_font_by_name("sans-serif", FONT_SIZE)
+            .build()
+            .and_then(|font| {
+                self.backend
+                    .text()
+                    .new_text_layout(&font, &label, None)
+                    .build()
+            });
+
+        match layout {
+            Ok(layout) => {
+                let position = kurbo::Point::new(
+                    midpoint.x - layout.width() / 2.0,
+                    midpoint.y - stroke_width - 2.0,
+                );
+                self.backend.draw_text(&layout, position, &colour);
+                self.draw_calls += 1;
+            },
+            Err(e) => log::warn!("Unable to lay out \"{}\": {}", label, e),
+        }
     }
 
     /// Translates a [`crate::Point`] from drawing space to a location in
@@ -206,31 +636,136 @@ impl<'window, 'world, B: RenderContext> System<'world>
         DrawOrder<'world>,
         Styling<'world>,
         ReadStorage<'world, Viewport>,
+        Option<Write<'world, RenderStats>>,
+        Option<Write<'world, DirtyRegions>>,
+        Option<Read<'world, DebugOverlay>>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (draw_order, styling, viewports) = data;
+        let (
+            draw_order,
+            styling,
+            viewports,
+            stats,
+            mut dirty_regions,
+            debug_overlay,
+        ) = data;
 
         let window_style = self.window.style(&styling.window_styles);
-        let viewport = self.window.viewport(&viewports);
+        let viewport = match self.viewport {
+            Some(ref viewport) => viewport.clone(),
+            None => self.window.viewport(&viewports).clone(),
+        };
+        let viewport = &viewport;
+
+        let redraw = self.redraw(viewport, dirty_regions.as_deref());
+        if let Some(ref mut dirty_regions) = dirty_regions {
+            dirty_regions.clear();
+        }
 
-        // make sure we're working with a blank screen
-        self.backend.clear(window_style.background_colour.clone());
+        let viewport_dimensions = match redraw {
+            Redraw::Nothing => return,
+            Redraw::Everything => {
+                // make sure we're working with a blank screen
+                self.backend.clear(window_style.background_colour.clone());
+                self.draw_calls = 1;
 
-        let viewport_dimensions = self.viewport_dimensions(&viewport);
+                self.viewport_dimensions(viewport)
+            },
+            Redraw::Region(region) => {
+                if let Err(e) = self.backend.save() {
+                    log::warn!("Unable to save the render context: {}", e);
+                }
+                self.backend.clip(region);
+                self.backend.fill(region, &window_style.background_colour);
+                self.draw_calls = 1;
+
+                self.drawing_area(viewport, region)
+            },
+        };
+
+        if window_style.show_axes {
+            self.render_axes(window_style, viewport, viewport_dimensions);
+        }
+
+        let candidates = draw_order.candidates(viewport_dimensions);
+        let mut drawn = 0;
 
-        for (ent, obj) in draw_order.calculate(viewport_dimensions) {
+        for (ent, obj) in draw_order.calculate(&candidates, viewport_dimensions)
+        {
             self.render(ent, obj, &styling, viewport);
+            drawn += 1;
+        }
+
+        if let Some(ref overlay) = debug_overlay {
+            self.render_debug_overlay(
+                overlay,
+                &draw_order,
+                &candidates,
+                viewport,
+                viewport_dimensions,
+            );
+        }
+
+        if let Redraw::Region(_) = redraw {
+            if let Err(e) = self.backend.restore() {
+                log::warn!("Unable to restore the render context: {}", e);
+            }
+        }
+
+        if let Some(mut stats) = stats {
+            let considered = (draw_order.drawing_objects.mask(), &candidates)
+                .join()
+                .count();
+
+            stats.considered += considered;
+            stats.culled += considered - drawn;
+            stats.drawn += drawn;
+            stats.draw_calls += self.draw_calls;
         }
     }
 }
 
+/// Counters for profiling the renderer.
+///
+/// Rendering will add to these counters whenever this resource is present in
+/// the [`World`], so you can reset it to get per-frame numbers or leave it to
+/// accumulate over several frames.
+///
+/// ```rust
+/// # use arcs::window::RenderStats;
+/// # use specs::prelude::*;
+/// let mut world = World::new();
+/// world.insert(RenderStats::default());
+///
+/// // ... render a frame ...
+///
+/// let stats = world.fetch::<RenderStats>();
+/// println!("Drew {} of {} objects", stats.drawn, stats.considered);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Objects which might be inside the viewport, according to the
+    /// [`Space`] (or every object, if it isn't available).
+    pub considered: usize,
+    /// Objects which were considered but skipped because they were outside
+    /// the viewport or on a hidden [`Layer`].
+    pub culled: usize,
+    /// Objects which were actually drawn.
+    pub drawn: usize,
+    /// The number of drawing operations (fills, strokes, etc.) sent to the
+    /// [`RenderContext`].
+    pub draw_calls: usize,
+}
+
 /// Styling information.
 #[derive(SystemData)]
 struct Styling<'world> {
     point_styles: ReadStorage<'world, PointStyle>,
     line_styles: ReadStorage<'world, LineStyle>,
     window_styles: ReadStorage<'world, WindowStyle>,
+    construction: ReadStorage<'world, Construction>,
+    hovered: ReadStorage<'world, Hovered>,
 }
 
 fn resolve_point_style<'a>(
@@ -262,19 +797,37 @@ fn resolve_line_style<'a>(
         .unwrap_or_else(|| window.default_line_style(&styling.line_styles))
 }
 
+/// The colour to draw `entity` with, which is `colour` unless the entity is
+/// [`Hovered`].
+fn resolve_colour(
+    styling: &Styling,
+    window: &Window,
+    entity: Entity,
+    colour: &Color,
+) -> Color {
+    if styling.hovered.contains(entity) {
+        window.style(&styling.window_styles).hover_colour.clone()
+    } else {
+        colour.clone()
+    }
+}
+
 /// The state needed when calculating which order to draw things in so z-levels
 /// are implemented correctly.
 #[derive(SystemData)]
 struct DrawOrder<'world> {
     entities: Entities<'world>,
     drawing_objects: ReadStorage<'world, DrawingObject>,
+    draw_priorities: ReadStorage<'world, DrawPriority>,
     layers: ReadStorage<'world, Layer>,
     bounding_boxes: ReadStorage<'world, BoundingBox<DrawingSpace>>,
+    space: Option<Read<'world, Space>>,
 }
 
 impl<'world> DrawOrder<'world> {
     fn calculate(
         &self,
+        candidates: &BitSet,
         viewport_dimensions: BoundingBox<DrawingSpace>,
     ) -> impl Iterator<Item = (Entity, &'_ DrawingObject)> + '_ {
         type EntitiesByZLevel<'a> =
@@ -286,37 +839,321 @@ impl<'world> DrawOrder<'world> {
         let mut drawing_objects = EntitiesByZLevel::new();
 
         // PERF: This function has a massive impact on render times
-        // Some ideas:
-        //   - Use a pre-calculated quad-tree so we just need to check items
-        //     within the viewport bounds
-        //   - use a entities-to-layers cache so we can skip checking whether to
-        //     draw an object on a hidden layer
 
-        for (ent, obj, bounds) in (
+        for (z_level, ent, obj) in
+            self.visible_objects(candidates, viewport_dimensions)
+        {
+            drawing_objects
+                .entry(Reverse(z_level))
+                .or_default()
+                .push((ent, obj));
+        }
+
+        // within a layer, objects with a higher priority are drawn last so
+        // they end up on top. The sort is stable, so objects with the same
+        // priority keep their original order.
+        for objects in drawing_objects.values_mut() {
+            objects.sort_by_key(|&(ent, _)| {
+                self.draw_priorities.get(ent).copied().unwrap_or_default()
+            });
+        }
+
+        drawing_objects.into_values().flatten()
+    }
+
+    /// Find all the objects which should be drawn, and their z-levels.
+    #[cfg(not(feature = "rayon"))]
+    fn visible_objects(
+        &self,
+        candidates: &BitSet,
+        viewport_dimensions: BoundingBox<DrawingSpace>,
+    ) -> Vec<(usize, Entity, &'_ DrawingObject)> {
+        (
             &self.entities,
             &self.drawing_objects,
             MaybeJoin(&self.bounding_boxes),
+            candidates,
         )
             .join()
-        {
-            let Layer { z_level, visible } = self
-                .layers
-                .get(obj.layer)
-                .expect("The object's layer was deleted");
-
-            // try to use the cached bounds, otherwise re-calculate them
-            let bounds = bounds
-                .copied()
-                .unwrap_or_else(|| obj.geometry.bounding_box());
-
-            if *visible && viewport_dimensions.intersects_with(bounds) {
-                drawing_objects
-                    .entry(Reverse(*z_level))
-                    .or_default()
-                    .push((ent, obj));
+            .filter_map(|(ent, obj, bounds, _)| {
+                self.z_level_if_visible(obj, bounds, viewport_dimensions)
+                    .map(|z_level| (z_level, ent, obj))
+            })
+            .collect()
+    }
+
+    /// Find all the objects which should be drawn, and their z-levels.
+    ///
+    /// This is identical to the serial version, except objects are checked
+    /// in parallel.
+    #[cfg(feature = "rayon")]
+    fn visible_objects(
+        &self,
+        candidates: &BitSet,
+        viewport_dimensions: BoundingBox<DrawingSpace>,
+    ) -> Vec<(usize, Entity, &'_ DrawingObject)> {
+        (
+            &self.entities,
+            &self.drawing_objects,
+            MaybeJoin(&self.bounding_boxes),
+            candidates,
+        )
+            .par_join()
+            .filter_map(|(ent, obj, bounds, _)| {
+                self.z_level_if_visible(obj, bounds, viewport_dimensions)
+                    .map(|z_level| (z_level, ent, obj))
+            })
+            // Note: collecting into a Vec preserves the join order
+            .collect()
+    }
+
+    /// Use the quadtree to find which objects might be inside the viewport.
+    ///
+    /// If [`Space`] is missing or hasn't been populated yet (e.g. because
+    /// [`crate::systems::SpatialRelation`] hasn't run) we fall back to checking
+    /// every [`DrawingObject`].
+    fn candidates(
+        &self,
+        viewport_dimensions: BoundingBox<DrawingSpace>,
+    ) -> BitSet {
+        match self.space {
+            Some(ref space) if !space.is_empty() => space
+                .query_region(viewport_dimensions)
+                .map(|spatial| spatial.entity.id())
+                .collect(),
+            _ => self.drawing_objects.mask().clone(),
+        }
+    }
+
+    /// Get the z-level to draw a [`DrawingObject`] at, or `None` if it
+    /// shouldn't be drawn.
+    fn z_level_if_visible(
+        &self,
+        obj: &DrawingObject,
+        bounds: Option<&BoundingBox<DrawingSpace>>,
+        viewport_dimensions: BoundingBox<DrawingSpace>,
+    ) -> Option<usize> {
+        let Layer { z_level, visible } = self
+            .layers
+            .get(obj.layer)
+            .expect("The object's layer was deleted");
+
+        // try to use the cached bounds, otherwise re-calculate them
+        let bounds = bounds.copied().unwrap_or_else(|| obj.bounds());
+
+        if *visible && viewport_dimensions.intersects_with(bounds) {
+            Some(*z_level)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::Bounded,
+        components::{Name, SpatialEntity},
+    };
+
+    #[test]
+    fn draw_order_yields_higher_z_levels_first() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let top = Layer::create(
+            world.create_entity(),
+            Name::new("top"),
+            Layer {
+                z_level: 1,
+                visible: true,
+            },
+        );
+        let bottom = Layer::create(
+            world.create_entity(),
+            Name::new("bottom"),
+            Layer {
+                z_level: 0,
+                visible: true,
+            },
+        );
+        let hidden = Layer::create(
+            world.create_entity(),
+            Name::new("hidden"),
+            Layer {
+                z_level: 2,
+                visible: false,
+            },
+        );
+        let mut create_point = |layer| {
+            world
+                .create_entity()
+                .with(DrawingObject::new(
+                    Geometry::Point(Point::new(1.0, 1.0)),
+                    layer,
+                ))
+                .build()
+        };
+        let first = create_point(bottom);
+        let second = create_point(top);
+        let _third = create_point(hidden);
+        let fourth = create_point(bottom);
+        let viewport =
+            BoundingBox::new(Point::new(-10.0, -10.0), Point::new(10.0, 10.0));
+
+        let draw_order: DrawOrder = world.system_data();
+        let candidates = draw_order.candidates(viewport);
+        let got: Vec<_> = draw_order
+            .calculate(&candidates, viewport)
+            .map(|(ent, _)| ent)
+            .collect();
+
+        assert_eq!(got, vec![second, first, fourth]);
+    }
+
+    #[test]
+    fn draw_priority_decides_the_order_within_a_layer() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        let mut create_point = |priority: Option<i32>| {
+            let builder = world.create_entity().with(DrawingObject::new(
+                Geometry::Point(Point::new(1.0, 1.0)),
+                layer,
+            ));
+            match priority {
+                Some(priority) => builder.with(DrawPriority(priority)),
+                None => builder,
             }
+            .build()
+        };
+        let front = create_point(Some(5));
+        let middle = create_point(None);
+        let back = create_point(Some(-1));
+        let viewport =
+            BoundingBox::new(Point::new(-10.0, -10.0), Point::new(10.0, 10.0));
+
+        let draw_order: DrawOrder = world.system_data();
+        let candidates = draw_order.candidates(viewport);
+        let got: Vec<_> = draw_order
+            .calculate(&candidates, viewport)
+            .map(|(ent, _)| ent)
+            .collect();
+
+        assert_eq!(got, vec![back, middle, front]);
+    }
+
+    #[test]
+    fn objects_on_a_hidden_layer_are_skipped() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        let point = world
+            .create_entity()
+            .with(DrawingObject::new(
+                Geometry::Point(Point::new(1.0, 1.0)),
+                layer,
+            ))
+            .build();
+        let viewport =
+            BoundingBox::new(Point::new(-10.0, -10.0), Point::new(10.0, 10.0));
+        let visible_objects = |world: &World| -> Vec<Entity> {
+            let draw_order: DrawOrder = world.system_data();
+            let candidates = draw_order.candidates(viewport);
+            draw_order
+                .calculate(&candidates, viewport)
+                .map(|(ent, _)| ent)
+                .collect()
+        };
+
+        assert_eq!(visible_objects(&world), vec![point]);
+
+        Layer::toggle_visibility(&world, layer);
+
+        assert!(visible_objects(&world).is_empty());
+    }
+
+    #[test]
+    fn only_objects_in_the_quadtree_query_are_drawn() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer {
+                z_level: 0,
+                visible: true,
+            },
+        );
+        let mut create_point = |location| {
+            let geometry = Geometry::Point(location);
+            world
+                .create_entity()
+                .with(geometry.bounding_box())
+                .with(DrawingObject::new(geometry, layer))
+                .build()
+        };
+        let inside = create_point(Point::new(1.0, 1.0));
+        let outside = create_point(Point::new(100.0, 100.0));
+        let mut space = Space::default();
+        for &ent in &[inside, outside] {
+            let bounds = *world.read_storage().get(ent).unwrap();
+            space.modify(SpatialEntity::new(bounds, ent));
         }
+        world.insert(space);
+        let viewport =
+            BoundingBox::new(Point::new(-10.0, -10.0), Point::new(10.0, 10.0));
+
+        let draw_order: DrawOrder = world.system_data();
+        let candidates = draw_order.candidates(viewport);
+        let got: Vec<_> = draw_order
+            .calculate(&candidates, viewport)
+            .map(|(ent, _)| ent)
+            .collect();
+
+        assert_eq!(got, vec![inside]);
+    }
+
+    #[test]
+    fn fit_the_viewport_to_the_drawing() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let window = Window::create(&mut world);
+        let layer = Layer::create(
+            world.create_entity(),
+            Name::new("default"),
+            Layer::default(),
+        );
+        let line = Line::new(Point::new(-10.0, 0.0), Point::new(30.0, 20.0));
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Line(line), layer))
+            .build();
+
+        window.fit_to_drawing(&world, Size2D::new(400.0, 400.0));
+
+        let viewport = window.viewport(&world.read_storage()).clone();
+        assert_eq!(viewport.centre, Point::new(10.0, 10.0));
+        assert_eq!(viewport.pixels_per_drawing_unit, Scale::new(10.0));
+    }
+
+    #[test]
+    fn fitting_an_empty_drawing_does_nothing() {
+        let mut world = World::new();
+        crate::components::register(&mut world);
+        let window = Window::create(&mut world);
+        let original = window.viewport(&world.read_storage()).clone();
+
+        window.fit_to_drawing(&world, Size2D::new(400.0, 400.0));
 
-        drawing_objects.into_iter().flat_map(|(_, items)| items)
+        assert_eq!(*window.viewport(&world.read_storage()), original);
     }
 }
@@ -0,0 +1,662 @@
+//! Integration tests for the render pipeline, using a [`RenderContext`] which
+//! records every drawing operation instead of actually drawing.
+
+use arcs::{
+    components::{
+        Construction, Dimension, DrawingObject, Geometry, Hovered, Layer,
+        LineStyle, Name, PointStyle, Space, SpatialEntity, Viewport,
+        WindowStyle,
+    },
+    window::{DebugOverlay, DirtyRegions, RenderStats, Window},
+    Angle, BoundingBox, Line, Point,
+};
+use euclid::{Scale, Size2D};
+use kurbo::{Affine, Circle, Rect, Shape};
+use piet::{
+    Color, Error, FixedGradient, ImageFormat, InterpolationMode, IntoBrush,
+    LineCap, LineJoin, NullText, NullTextLayout, RenderContext, StrokeStyle,
+};
+use specs::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// A drawing operation, with shapes reduced to their bounding boxes and
+/// colours to their RGBA value.
+#[derive(Debug, Clone, PartialEq)]
+enum Call {
+    Clear(u32),
+    Clip(Rect),
+    Fill {
+        bounds: Rect,
+        colour: u32,
+    },
+    Stroke {
+        bounds: Rect,
+        colour: u32,
+        width: f64,
+        dashed: bool,
+        cap: Option<LineCap>,
+        join: Option<LineJoin>,
+    },
+}
+
+/// A [`RenderContext`] which records the calls made to it.
+struct Recorder {
+    calls: Rc<RefCell<Vec<Call>>>,
+    text: NullText,
+}
+
+impl Recorder {
+    fn new() -> (Recorder, Rc<RefCell<Vec<Call>>>) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let recorder = Recorder {
+            calls: Rc::clone(&calls),
+            text: NullText,
+        };
+
+        (recorder, calls)
+    }
+
+    fn colour_of(&mut self, brush: &impl IntoBrush<Self>, bounds: Rect) -> u32 {
+        brush.make_brush(self, || bounds).as_rgba_u32()
+    }
+}
+
+impl RenderContext for Recorder {
+    type Brush = Color;
+    type Image = ();
+    type Text = NullText;
+    type TextLayout = NullTextLayout;
+
+    fn status(&mut self) -> Result<(), Error> { Ok(()) }
+
+    fn solid_brush(&mut self, color: Color) -> Self::Brush { color }
+
+    fn gradient(
+        &mut self,
+        _gradient: impl Into<FixedGradient>,
+    ) -> Result<Self::Brush, Error> {
+        Err(Error::NotSupported)
+    }
+
+    fn clear(&mut self, color: Color) {
+        self.calls
+            .borrow_mut()
+            .push(Call::Clear(color.as_rgba_u32()));
+    }
+
+    fn stroke(
+        &mut self,
+        shape: impl Shape,
+        brush: &impl IntoBrush<Self>,
+        width: f64,
+    ) {
+        self.stroke_styled(shape, brush, width, &StrokeStyle::new());
+    }
+
+    fn stroke_styled(
+        &mut self,
+        shape: impl Shape,
+        brush: &impl IntoBrush<Self>,
+        width: f64,
+        style: &StrokeStyle,
+    ) {
+        let bounds = shape.bounding_box();
+        let colour = self.colour_of(brush, bounds);
+        self.calls.borrow_mut().push(Call::Stroke {
+            bounds,
+            colour,
+            width,
+            dashed: style.dash.is_some(),
+            cap: style.line_cap,
+            join: style.line_join,
+        });
+    }
+
+    fn fill(&mut self, shape: impl Shape, brush: &impl IntoBrush<Self>) {
+        let bounds = shape.bounding_box();
+        let colour = self.colour_of(brush, bounds);
+        self.calls.borrow_mut().push(Call::Fill { bounds, colour });
+    }
+
+    fn fill_even_odd(
+        &mut self,
+        shape: impl Shape,
+        brush: &impl IntoBrush<Self>,
+    ) {
+        self.fill(shape, brush);
+    }
+
+    fn clip(&mut self, shape: impl Shape) {
+        self.calls
+            .borrow_mut()
+            .push(Call::Clip(shape.bounding_box()));
+    }
+
+    fn text(&mut self) -> &mut Self::Text { &mut self.text }
+
+    fn draw_text(
+        &mut self,
+        _layout: &Self::TextLayout,
+        _pos: impl Into<kurbo::Point>,
+        _brush: &impl IntoBrush<Self>,
+    ) {
+    }
+
+    fn save(&mut self) -> Result<(), Error> { Ok(()) }
+
+    fn restore(&mut self) -> Result<(), Error> { Ok(()) }
+
+    fn finish(&mut self) -> Result<(), Error> { Ok(()) }
+
+    fn transform(&mut self, _transform: Affine) {}
+
+    fn make_image(
+        &mut self,
+        _width: usize,
+        _height: usize,
+        _buf: &[u8],
+        _format: ImageFormat,
+    ) -> Result<Self::Image, Error> {
+        Err(Error::NotSupported)
+    }
+
+    fn draw_image(
+        &mut self,
+        _image: &Self::Image,
+        _dst_rect: impl Into<Rect>,
+        _interp: InterpolationMode,
+    ) {
+    }
+
+    fn draw_image_area(
+        &mut self,
+        _image: &Self::Image,
+        _src_rect: impl Into<Rect>,
+        _dst_rect: impl Into<Rect>,
+        _interp: InterpolationMode,
+    ) {
+    }
+
+    fn blurred_rect(
+        &mut self,
+        _rect: Rect,
+        _blur_radius: f64,
+        _brush: &impl IntoBrush<Self>,
+    ) {
+    }
+
+    fn current_transform(&self) -> Affine { Affine::default() }
+}
+
+fn render(world: &World, window: &Window) -> Vec<Call> {
+    let (recorder, calls) = Recorder::new();
+
+    {
+        let mut system =
+            window.render_system(recorder, Size2D::new(200.0, 100.0));
+        RunNow::run_now(&mut system, world);
+    }
+
+    calls.replace(Vec::new())
+}
+
+fn render_incrementally(world: &World, window: &Window) -> Vec<Call> {
+    let (recorder, calls) = Recorder::new();
+
+    {
+        let mut system = window
+            .incremental_render_system(recorder, Size2D::new(200.0, 100.0));
+        RunNow::run_now(&mut system, world);
+    }
+
+    calls.replace(Vec::new())
+}
+
+fn world_with_window() -> (World, Window) {
+    let mut world = World::new();
+    arcs::components::register(&mut world);
+    let window = Window::create(&mut world);
+
+    *window.viewport_mut(&mut world.write_storage()) = Viewport {
+        centre: Point::zero(),
+        pixels_per_drawing_unit: Scale::new(1.0),
+        rotation: Angle::zero(),
+        y_up: true,
+    };
+    window
+        .style_mut(&mut world.write_storage())
+        .background_colour = Color::WHITE;
+
+    (world, window)
+}
+
+#[test]
+fn render_a_point_and_a_line() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Point(Point::new(10.0, 20.0)),
+            layer,
+        ))
+        .with(PointStyle {
+            radius: Dimension::Pixels(3.0),
+            colour: Color::rgb8(0, 0xff, 0),
+        })
+        .build();
+    world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Line(Line::new(
+                Point::new(-50.0, -10.0),
+                Point::new(50.0, -10.0),
+            )),
+            layer,
+        ))
+        .with(LineStyle {
+            width: Dimension::Pixels(2.0),
+            stroke: Color::rgb8(0xff, 0, 0),
+            ..Default::default()
+        })
+        .build();
+
+    let mut got = render(&world, &window);
+
+    // the background is always cleared first, but objects on the same layer
+    // may be drawn in any order
+    assert_eq!(got.remove(0), Call::Clear(Color::WHITE.as_rgba_u32()));
+    assert_eq!(got.len(), 2);
+    let point = Call::Fill {
+        bounds: Circle::new((110.0, 30.0), 3.0).bounding_box(),
+        colour: Color::rgb8(0, 0xff, 0).as_rgba_u32(),
+    };
+    let line = Call::Stroke {
+        bounds: Rect::new(50.0, 60.0, 150.0, 60.0),
+        colour: Color::rgb8(0xff, 0, 0).as_rgba_u32(),
+        width: 2.0,
+        dashed: false,
+        cap: Some(LineCap::Butt),
+        join: Some(LineJoin::Miter),
+    };
+    assert!(got.contains(&point), "{:?} not in {:?}", point, got);
+    assert!(got.contains(&line), "{:?} not in {:?}", line, got);
+}
+
+#[test]
+fn hidden_layers_are_not_drawn() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("hidden"),
+        Layer {
+            z_level: 0,
+            visible: false,
+        },
+    );
+    world
+        .create_entity()
+        .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+        .build();
+
+    let got = render(&world, &window);
+
+    assert_eq!(got, vec![Call::Clear(Color::WHITE.as_rgba_u32())]);
+}
+
+#[test]
+fn objects_outside_the_viewport_are_culled() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Point(Point::new(1000.0, 1000.0)),
+            layer,
+        ))
+        .build();
+
+    let got = render(&world, &window);
+
+    assert_eq!(got, vec![Call::Clear(Color::WHITE.as_rgba_u32())]);
+}
+
+#[test]
+fn objects_partially_inside_the_viewport_are_drawn() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Line(Line::new(
+                Point::new(-1000.0, 0.0),
+                Point::new(1000.0, 0.0),
+            )),
+            layer,
+        ))
+        .build();
+
+    let got = render(&world, &window);
+
+    assert_eq!(got.len(), 2);
+    assert!(matches!(got[1], Call::Stroke { .. }));
+}
+
+#[test]
+fn construction_geometry_is_dashed() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Line(Line::new(
+                Point::new(-10.0, 0.0),
+                Point::new(10.0, 0.0),
+            )),
+            layer,
+        ))
+        .with(Construction)
+        .build();
+
+    let got = render(&world, &window);
+
+    assert_eq!(got.len(), 2);
+    assert!(matches!(got[1], Call::Stroke { dashed: true, .. }));
+}
+
+#[test]
+fn hovered_objects_use_the_hover_colour() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    let line = world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Line(Line::new(
+                Point::new(-10.0, 0.0),
+                Point::new(10.0, 0.0),
+            )),
+            layer,
+        ))
+        .build();
+    let hover_colour = WindowStyle::default().hover_colour.as_rgba_u32();
+
+    let got = render(&world, &window);
+    assert!(
+        matches!(got[1], Call::Stroke { colour, .. } if colour != hover_colour)
+    );
+
+    assert!(Hovered::set(&world, Some(line)));
+    let got = render(&world, &window);
+    assert!(
+        matches!(got[1], Call::Stroke { colour, .. } if colour == hover_colour)
+    );
+}
+
+#[test]
+fn lines_use_their_cap_and_join() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Line(Line::new(
+                Point::new(-10.0, 0.0),
+                Point::new(10.0, 0.0),
+            )),
+            layer,
+        ))
+        .with(LineStyle {
+            width: Dimension::Pixels(5.0),
+            cap: LineCap::Round,
+            join: LineJoin::Bevel,
+            ..Default::default()
+        })
+        .build();
+
+    let got = render(&world, &window);
+
+    assert_eq!(got.len(), 2);
+    assert!(matches!(
+        got[1],
+        Call::Stroke {
+            cap: Some(LineCap::Round),
+            join: Some(LineJoin::Bevel),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn collect_render_stats() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    for &location in &[Point::zero(), Point::new(1000.0, 1000.0)] {
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(location), layer))
+            .build();
+    }
+    world.insert(RenderStats::default());
+
+    render(&world, &window);
+    render(&world, &window);
+
+    let expected = RenderStats {
+        considered: 4,
+        culled: 2,
+        drawn: 2,
+        // a clear and a fill each frame
+        draw_calls: 4,
+    };
+    assert_eq!(*world.fetch::<RenderStats>(), expected);
+}
+
+#[test]
+fn debug_overlay_strokes_bounding_boxes_and_quadtree_nodes() {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    let bounds = BoundingBox::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    let point = world
+        .create_entity()
+        .with(DrawingObject::new(
+            Geometry::Point(Point::new(1.0, 1.0)),
+            layer,
+        ))
+        .with(bounds)
+        .build();
+    let mut space = Space::default();
+    space.modify(SpatialEntity::new(bounds, point));
+    world.insert(space);
+
+    // the overlay is opt-in
+    assert_eq!(render(&world, &window).len(), 2);
+
+    let overlay = DebugOverlay::default();
+    world.insert(overlay.clone());
+    let got = render(&world, &window);
+
+    assert_eq!(got.len(), 4);
+    // the quadtree's root node covers the entire canvas
+    assert!(matches!(
+        got[2],
+        Call::Stroke { bounds, colour, .. }
+            if colour == overlay.quadtree_colour.as_rgba_u32()
+                && bounds.union(Rect::new(0.0, 0.0, 200.0, 100.0)) == bounds
+    ));
+    assert_eq!(
+        got[3],
+        Call::Stroke {
+            bounds: Rect::new(100.0, 48.0, 102.0, 50.0),
+            colour: overlay.bounding_box_colour.as_rgba_u32(),
+            width: 1.0,
+            dashed: false,
+            cap: None,
+            join: None,
+        }
+    );
+
+    world.insert(DebugOverlay {
+        show_quadtree: false,
+        ..overlay
+    });
+    assert_eq!(render(&world, &window).len(), 3);
+}
+
+/// A world with one point near the origin and another in the far corner.
+fn world_with_two_points() -> (World, Window) {
+    let (mut world, window) = world_with_window();
+    let layer = Layer::create(
+        world.create_entity(),
+        Name::new("default"),
+        Layer::default(),
+    );
+    for &location in &[Point::new(1.0, 1.0), Point::new(90.0, 40.0)] {
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(location), layer))
+            .build();
+    }
+
+    (world, window)
+}
+
+#[test]
+fn only_repaint_dirty_regions() {
+    let (mut world, window) = world_with_two_points();
+    let mut dirty = DirtyRegions::default();
+    dirty.mark(BoundingBox::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)));
+    world.insert(dirty);
+
+    let got = render_incrementally(&world, &window);
+
+    // (0, 0) to (2, 2) in the drawing is (100, 48) to (102, 50) on the
+    // canvas, plus a margin
+    let region = Rect::new(90.0, 38.0, 112.0, 60.0);
+    assert_eq!(got.len(), 3, "{:?}", got);
+    assert_eq!(got[0], Call::Clip(region));
+    assert_eq!(
+        got[1],
+        Call::Fill {
+            bounds: region,
+            colour: Color::WHITE.as_rgba_u32()
+        }
+    );
+    assert!(matches!(got[2], Call::Fill { .. }));
+    assert!(world.fetch::<DirtyRegions>().is_empty());
+}
+
+#[test]
+fn nothing_is_drawn_when_nothing_is_dirty() {
+    let (mut world, window) = world_with_two_points();
+    world.insert(DirtyRegions::default());
+
+    let got = render_incrementally(&world, &window);
+
+    assert!(got.is_empty(), "{:?}", got);
+}
+
+#[test]
+fn large_dirty_regions_repaint_everything() {
+    let (mut world, window) = world_with_two_points();
+    let mut dirty = DirtyRegions::default();
+    dirty.mark(BoundingBox::new(
+        Point::new(-100.0, -50.0),
+        Point::new(100.0, 50.0),
+    ));
+    world.insert(dirty);
+
+    let got = render_incrementally(&world, &window);
+
+    assert_eq!(got[0], Call::Clear(Color::WHITE.as_rgba_u32()));
+    assert_eq!(got.len(), 3);
+}
+
+#[test]
+fn repaint_everything_without_dirty_regions() {
+    let (world, window) = world_with_two_points();
+
+    let got = render_incrementally(&world, &window);
+
+    assert_eq!(got[0], Call::Clear(Color::WHITE.as_rgba_u32()));
+    assert_eq!(got.len(), 3);
+}
+
+#[test]
+fn higher_layers_are_drawn_last() {
+    let (mut world, window) = world_with_window();
+    let top = Layer::create(
+        world.create_entity(),
+        Name::new("top"),
+        Layer {
+            z_level: 0,
+            visible: true,
+        },
+    );
+    let bottom = Layer::create(
+        world.create_entity(),
+        Name::new("bottom"),
+        Layer {
+            z_level: 1,
+            visible: true,
+        },
+    );
+    for (layer, colour) in [(top, Color::BLACK), (bottom, Color::WHITE)]
+        .iter()
+        .cloned()
+    {
+        world
+            .create_entity()
+            .with(DrawingObject::new(Geometry::Point(Point::zero()), layer))
+            .with(PointStyle {
+                radius: Dimension::Pixels(1.0),
+                colour,
+            })
+            .build();
+    }
+
+    let got = render(&world, &window);
+
+    let colours: Vec<_> = got
+        .iter()
+        .filter_map(|call| match call {
+            Call::Fill { colour, .. } => Some(*colour),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        colours,
+        vec![Color::WHITE.as_rgba_u32(), Color::BLACK.as_rgba_u32()]
+    );
+}
@@ -1,4 +1,7 @@
-use crate::primitives::Line;
+use crate::{
+    primitives::{Arc, Ellipse, EllipticalArc, Line},
+    Angle, BoundingBox,
+};
 use euclid::default::Transform2D;
 
 /// Something which can be transformed using an arbitrary [`Transform2D`] matrix
@@ -46,7 +49,7 @@ pub trait AffineTransformable {
     }
 }
 
-impl<'t, T: AffineTransformable + ?Sized> AffineTransformable for &'t mut T {
+impl<T: AffineTransformable + ?Sized> AffineTransformable for &mut T {
     fn transform(&mut self, transform: Transform2D<f64>) {
         (*self).transform(transform);
     }
@@ -76,3 +79,241 @@ impl<Space> AffineTransformable for Line<Space> {
         self.end.transform(transform);
     }
 }
+
+impl<Space> AffineTransformable for Arc<Space> {
+    /// Transform the [`Arc`], assuming `transform` only rotates, mirrors,
+    /// translates, or scales uniformly.
+    ///
+    /// Any other transform would turn the [`Arc`] into an ellipse, so the
+    /// radius will be scaled by the geometric mean of the scale factors
+    /// (`√|det|`) instead. See
+    /// [`crate::algorithms::scale_arc_non_uniform()`] if you need the true
+    /// shape.
+    fn transform(&mut self, transform: Transform2D<f64>) {
+        let determinant = transform.determinant();
+        // where the x-axis ends up tells us how much we've been rotated
+        let rotation = Angle::radians(f64::atan2(transform.m12, transform.m11));
+
+        let mut centre = self.centre();
+        centre.transform(transform);
+        let radius = self.radius() * determinant.abs().sqrt();
+
+        *self = if determinant < 0.0 {
+            // mirroring reverses the direction we sweep in
+            Arc::from_centre_radius(
+                centre,
+                radius,
+                rotation - self.start_angle(),
+                -self.sweep_angle(),
+            )
+        } else {
+            Arc::from_centre_radius(
+                centre,
+                radius,
+                self.start_angle() + rotation,
+                self.sweep_angle(),
+            )
+        };
+    }
+}
+
+impl<Space> AffineTransformable for EllipticalArc<Space> {
+    /// Transform the [`EllipticalArc`].
+    ///
+    /// Unlike an [`Arc`], any affine transform of an ellipse is still an
+    /// ellipse, so this is exact.
+    fn transform(&mut self, transform: Transform2D<f64>) {
+        let ellipse = self.ellipse();
+        let mut centre = ellipse.centre();
+        centre.transform(transform);
+
+        // the ellipse is the unit circle mapped through
+        // `rotate(rotation) * scale(major, minor)`, so combine that with the
+        // transform's linear part and decompose the result using a closed
+        // form SVD, `rotate(phi) * scale(sx, sy) * rotate(theta)`
+        let (rot_sin, rot_cos) = ellipse.rotation().sin_cos();
+        let (a, b) = (ellipse.major_radius(), ellipse.minor_radius());
+        let (m00, m01, m10, m11) = (
+            a * (transform.m11 * rot_cos + transform.m21 * rot_sin),
+            b * (transform.m21 * rot_cos - transform.m11 * rot_sin),
+            a * (transform.m12 * rot_cos + transform.m22 * rot_sin),
+            b * (transform.m22 * rot_cos - transform.m12 * rot_sin),
+        );
+
+        let e = (m00 + m11) / 2.0;
+        let f = (m00 - m11) / 2.0;
+        let g = (m10 + m01) / 2.0;
+        let h = (m10 - m01) / 2.0;
+        let q = f64::hypot(e, h);
+        let r = f64::hypot(f, g);
+        let (sx, sy) = (q + r, q - r);
+        let a1 = f64::atan2(g, f);
+        let a2 = f64::atan2(h, e);
+        let theta = Angle::radians((a2 - a1) / 2.0);
+        let phi = Angle::radians((a2 + a1) / 2.0);
+
+        let ellipse = Ellipse::new(centre, sx, sy.abs(), phi);
+
+        *self = if sy < 0.0 {
+            // mirroring reverses the direction we sweep in
+            EllipticalArc::new(
+                ellipse,
+                -(self.start_angle() + theta),
+                -self.sweep_angle(),
+            )
+        } else {
+            EllipticalArc::new(
+                ellipse,
+                self.start_angle() + theta,
+                self.sweep_angle(),
+            )
+        };
+    }
+}
+
+impl<Space> AffineTransformable for Ellipse<Space> {
+    fn transform(&mut self, transform: Transform2D<f64>) {
+        *self = EllipticalArc::full(*self).transformed(transform).ellipse();
+    }
+}
+
+impl<Space> AffineTransformable for BoundingBox<Space> {
+    /// Transform all four corners, then take the [`BoundingBox`] around them.
+    ///
+    /// This means the box will grow when rotated or sheared so it still
+    /// contains everything the original box did.
+    fn transform(&mut self, transform: Transform2D<f64>) {
+        let corners = [
+            self.bottom_left(),
+            self.bottom_right(),
+            self.top_right(),
+            self.top_left(),
+        ];
+
+        *self = BoundingBox::around(
+            corners.iter().map(|corner| corner.transformed(transform)),
+        )
+        .expect("There are always 4 corners");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    #[test]
+    fn rotating_a_bounding_box_grows_it() {
+        let original =
+            BoundingBox::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0));
+        let rotation = Transform2D::create_rotation(Angle::degrees(45.0));
+
+        let got = original.transformed(rotation);
+
+        let half_diagonal = 2.0_f64.sqrt();
+        assert!(got
+            .bottom_left()
+            .approx_eq(&Point::new(-half_diagonal, -half_diagonal)));
+        assert!(got
+            .top_right()
+            .approx_eq(&Point::new(half_diagonal, half_diagonal)));
+    }
+
+    #[test]
+    fn mirroring_a_bounding_box_keeps_its_corners_ordered() {
+        let original =
+            BoundingBox::new(Point::new(1.0, 2.0), Point::new(3.0, 5.0));
+        let mirror = Transform2D::create_scale(-1.0, 1.0);
+
+        let got = original.transformed(mirror);
+
+        assert_eq!(
+            got,
+            BoundingBox::new(Point::new(-3.0, 2.0), Point::new(-1.0, 5.0))
+        );
+    }
+
+    #[test]
+    fn rotate_an_arc() {
+        let original = Arc::from_centre_radius(
+            Point::new(1.0, 0.0),
+            2.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let rotation = Transform2D::create_rotation(Angle::frac_pi_2());
+
+        let got = original.transformed(rotation);
+
+        let centre = original.centre().transformed(rotation);
+        assert!(got.centre().approx_eq(&centre));
+        assert_eq!(got.radius(), 2.0);
+        assert!(got
+            .start()
+            .approx_eq(&original.start().transformed(rotation)));
+        assert!(got.end().approx_eq(&original.end().transformed(rotation)));
+        assert!(got.is_anticlockwise());
+    }
+
+    #[test]
+    fn mirror_an_arc() {
+        let original = Arc::from_centre_radius(
+            Point::new(1.0, 0.0),
+            2.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let mirror = Transform2D::create_scale(-1.0, 1.0);
+
+        let got = original.transformed(mirror);
+
+        assert!(got.centre().approx_eq(&Point::new(-1.0, 0.0)));
+        assert!(got.start().approx_eq(&Point::new(-3.0, 0.0)));
+        assert!(got.end().approx_eq(&Point::new(-1.0, 2.0)));
+        assert!(got.is_clockwise());
+    }
+
+    #[test]
+    fn non_uniformly_scaled_arc_becomes_an_ellipse() {
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 1.0),
+            2.0,
+            Angle::degrees(10.0),
+            Angle::degrees(200.0),
+        );
+        let transform = Transform2D::create_rotation(Angle::degrees(30.0))
+            .post_scale(3.0, 0.5)
+            .post_translate(euclid::vec2(-4.0, 2.0));
+
+        let got = EllipticalArc::from(arc).transformed(transform);
+
+        for i in 0..=10 {
+            let angle = arc.sweep_angle() * (i as f64 / 10.0);
+            let expected = arc.point_at(angle).transformed(transform);
+            assert!(got.point_at(angle).approx_eq(&expected));
+        }
+        assert!(got.ellipse().major_radius() >= got.ellipse().minor_radius());
+    }
+
+    #[test]
+    fn mirrored_ellipse_sweeps_the_other_way() {
+        let ellipse =
+            Ellipse::new(Point::new(1.0, 2.0), 3.0, 1.0, Angle::degrees(20.0));
+        let original = EllipticalArc::new(
+            ellipse,
+            Angle::degrees(45.0),
+            Angle::degrees(90.0),
+        );
+        let transform = Transform2D::create_scale(-1.0, 2.0);
+
+        let got = original.transformed(transform);
+
+        assert!(got.is_clockwise());
+        assert!(got
+            .start()
+            .approx_eq(&original.start().transformed(transform)));
+        assert!(got.end().approx_eq(&original.end().transformed(transform)));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    primitives::{Arc, Line},
+    primitives::{Arc, Ellipse, EllipticalArc, Line},
     Angle,
 };
 use euclid::Point2D;
@@ -18,7 +18,7 @@ pub trait Approximate<Space> {
     fn approximate(&self, tolerance: f64) -> Self::Iter;
 }
 
-impl<'a, Space, A: Approximate<Space> + ?Sized> Approximate<Space> for &'a A {
+impl<Space, A: Approximate<Space> + ?Sized> Approximate<Space> for &A {
     type Iter = A::Iter;
 
     fn approximate(&self, tolerance: f64) -> Self::Iter {
@@ -46,34 +46,8 @@ impl<Space> Approximate<Space> for Arc<Space> {
     type Iter = ApproximatedArc<Space>;
 
     fn approximate(&self, tolerance: f64) -> Self::Iter {
-        // Draw a chord between points A and B on a circle with centre C.
-        // Draw a line which bisects the angle ACB and intersects with the
-        // chord at point D.
-        // The distance from D to the arc is our "quality"
-        // (i.e. |CD| + quality = radius).
-        //
-        // From the triangle DCB:
-        //   cos(θ/2) = |CD|/R
-        //   cos(θ/2) = 1 - quality/R
-        //
-        //  where θ is the angle swept by a chord with the desired "quality".
-        //
-        // # line segments to approximate with the specified quality:
-        //   N = ⌈SweepAngle/θ⌉
-
-        let (steps, delta) = if tolerance <= 0.0 || self.radius() <= tolerance {
-            (1, self.sweep_angle())
-        } else {
-            let cos_theta_on_two = 1.0 - tolerance / self.radius();
-            let theta = cos_theta_on_two.acos() * 2.0;
-            let line_segment_count = self.sweep_angle().get() / theta;
-
-            // make sure we always have at least 2 points
-            let line_segment_count = f64::max(line_segment_count, 2.0);
-            let actual_step = self.sweep_angle() / line_segment_count;
-
-            (line_segment_count.ceil().abs() as usize, actual_step)
-        };
+        let (steps, delta) =
+            chord_steps(self.radius(), self.sweep_angle(), tolerance);
 
         ApproximatedArc {
             i: 0,
@@ -84,6 +58,71 @@ impl<Space> Approximate<Space> for Arc<Space> {
     }
 }
 
+/// Work out how many equal steps to divide `sweep` into so chords on a circle
+/// of the given `radius` stay within `tolerance` of the curve, returning the
+/// number of steps and the angle covered by each.
+fn chord_steps(radius: f64, sweep: Angle, tolerance: f64) -> (usize, Angle) {
+    // Draw a chord between points A and B on a circle with centre C.
+    // Draw a line which bisects the angle ACB and intersects with the
+    // chord at point D.
+    // The distance from D to the arc is our "quality"
+    // (i.e. |CD| + quality = radius).
+    //
+    // From the triangle DCB:
+    //   cos(θ/2) = |CD|/R
+    //   cos(θ/2) = 1 - quality/R
+    //
+    //  where θ is the angle swept by a chord with the desired "quality".
+    //
+    // # line segments to approximate with the specified quality:
+    //   N = ⌈SweepAngle/θ⌉
+
+    if tolerance <= 0.0 || radius <= tolerance {
+        (1, sweep)
+    } else {
+        let cos_theta_on_two = 1.0 - tolerance / radius;
+        let theta = cos_theta_on_two.acos() * 2.0;
+        let line_segment_count = sweep.get().abs() / theta;
+
+        // make sure we always have at least 2 line segments, and that they
+        // evenly divide the sweep so the last point lands on the end
+        let line_segment_count = f64::max(line_segment_count, 2.0).ceil();
+        let actual_step = sweep / line_segment_count;
+
+        (line_segment_count as usize, actual_step)
+    }
+}
+
+impl<Space> Approximate<Space> for EllipticalArc<Space> {
+    type Iter = ApproximatedEllipticalArc<Space>;
+
+    /// The ellipse is the unit circle stretched by at most the major radius,
+    /// and equal parametric steps on the circle map to chords on the ellipse,
+    /// so we can use the same step size as a circle with the major radius.
+    fn approximate(&self, tolerance: f64) -> Self::Iter {
+        let (steps, step_size) = chord_steps(
+            self.ellipse().major_radius(),
+            self.sweep_angle(),
+            tolerance,
+        );
+
+        ApproximatedEllipticalArc {
+            i: 0,
+            steps,
+            step_size,
+            arc: *self,
+        }
+    }
+}
+
+impl<Space> Approximate<Space> for Ellipse<Space> {
+    type Iter = ApproximatedEllipticalArc<Space>;
+
+    fn approximate(&self, tolerance: f64) -> Self::Iter {
+        EllipticalArc::full(*self).approximate(tolerance)
+    }
+}
+
 /// An iterator over the points in an arc approximation.
 ///
 /// This shouldn't be used directly, you are probably looking for
@@ -112,9 +151,38 @@ impl<Space> Iterator for ApproximatedArc<Space> {
     }
 }
 
+/// An iterator over the points in an elliptical arc approximation.
+///
+/// This shouldn't be used directly, you are probably looking for
+/// `EllipticalArc::approximate()`.
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)] // iterators which are Copy are a footgun
+pub struct ApproximatedEllipticalArc<Space> {
+    i: usize,
+    steps: usize,
+    step_size: Angle,
+    arc: EllipticalArc<Space>,
+}
+
+impl<Space> Iterator for ApproximatedEllipticalArc<Space> {
+    type Item = Point2D<f64, Space>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.steps {
+            return None;
+        }
+
+        let angle = Angle::radians(self.i as f64 * self.step_size.radians);
+        let point = self.arc.point_at(angle);
+        self.i += 1;
+        Some(point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use euclid::approxeq::ApproxEq;
 
     type Point = euclid::default::Point2D<f64>;
 
@@ -131,10 +199,72 @@ mod tests {
         let pieces: Vec<_> = arc.approximate(quality).collect();
 
         for &piece in &pieces {
-            let error = arc.radius() - (piece - arc.centre()).length();
+            let error = arc.radius() - piece.distance_to(arc.centre());
             assert!(error < quality);
         }
         assert_eq!(arc.start(), *pieces.first().unwrap());
         assert_eq!(arc.end(), *pieces.last().unwrap());
     }
+
+    #[test]
+    fn approximation_ends_at_the_end_of_the_arc() {
+        let arcs = [
+            Arc::from_centre_radius(
+                Point::zero(),
+                10.0,
+                Angle::zero(),
+                Angle::pi(),
+            ),
+            Arc::from_centre_radius(
+                Point::zero(),
+                10.0,
+                Angle::zero(),
+                -Angle::pi(),
+            ),
+        ];
+
+        for arc in arcs.iter() {
+            let pieces: Vec<_> = arc.approximate(0.01).collect();
+
+            // both directions need the same number of pieces
+            assert!(pieces.len() > 10);
+            assert!(pieces.last().unwrap().approx_eq(&arc.end()));
+            for &piece in &pieces {
+                assert!(
+                    arc.contains_angle(Line::new(arc.centre(), piece).angle())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn approximate_elliptical_arc_with_points() {
+        let ellipse = Ellipse::new(
+            Point::new(1.0, 1.0),
+            50.0,
+            10.0,
+            Angle::degrees(30.0),
+        );
+        let arc = EllipticalArc::new(
+            ellipse,
+            Angle::degrees(10.0),
+            Angle::degrees(-250.0),
+        );
+        let tolerance = 0.1;
+
+        let pieces: Vec<_> = arc.approximate(tolerance).collect();
+
+        assert_eq!(arc.start(), pieces[0]);
+        assert!(arc.end().approx_eq(&pieces[pieces.len() - 1]));
+
+        // the mid-point of each chord should be close to the ellipse
+        let steps = pieces.len() - 1;
+        for (i, chord) in pieces.windows(2).enumerate() {
+            let mid = chord[0].lerp(chord[1], 0.5);
+            let on_curve = arc.point_at(
+                arc.sweep_angle() * ((i as f64 + 0.5) / steps as f64),
+            );
+            assert!(mid.distance_to(on_curve) <= tolerance);
+        }
+    }
 }
@@ -0,0 +1,200 @@
+use crate::{algorithms::TransformBuilder, Angle};
+use euclid::{Point2D, Transform2D, Vector2D};
+
+/// A pattern for making an array of copies of some geometry.
+///
+/// The original is always the first item in the array, so it doesn't get a
+/// transform of its own.
+#[derive(Debug, PartialEq)]
+pub enum ArrayPattern<S> {
+    /// Copies laid out in a grid of `rows` by `cols`.
+    Rectangular {
+        /// The number of rows, including the original.
+        rows: usize,
+        /// The number of columns, including the original.
+        cols: usize,
+        /// The offset between one row and the next.
+        row_spacing: Vector2D<f64, S>,
+        /// The offset between one column and the next.
+        col_spacing: Vector2D<f64, S>,
+    },
+    /// Copies spread around a circle.
+    Polar {
+        /// The number of items, including the original.
+        count: usize,
+        /// The point being rotated around.
+        centre: Point2D<f64, S>,
+        /// The angle covered by the array, anti-clockwise from the original.
+        /// Copies are spaced evenly, and a full revolution won't place a copy
+        /// on top of the original.
+        total_angle: Angle,
+        /// Should each copy be rotated to follow the array, or keep its
+        /// original orientation?
+        rotate_copies: bool,
+    },
+}
+
+impl<S> ArrayPattern<S> {
+    /// Get the transforms which place each copy, given a `reference` point on
+    /// the original (e.g. the centre of its bounding box).
+    ///
+    /// The `reference` is only used by [`ArrayPattern::Polar`] arrays which
+    /// don't rotate their copies, where it is the point that gets moved
+    /// around the circle.
+    pub fn transforms(
+        &self,
+        reference: Point2D<f64, S>,
+    ) -> Vec<Transform2D<f64, S, S>> {
+        match *self {
+            ArrayPattern::Rectangular {
+                rows,
+                cols,
+                row_spacing,
+                col_spacing,
+            } => (0..rows)
+                .flat_map(|row| (0..cols).map(move |col| (row, col)))
+                .skip(1)
+                .map(|(row, col)| {
+                    let offset =
+                        row_spacing * row as f64 + col_spacing * col as f64;
+                    Transform2D::create_translation(offset.x, offset.y)
+                })
+                .collect(),
+            ArrayPattern::Polar {
+                count,
+                centre,
+                total_angle,
+                rotate_copies,
+            } => {
+                let step = polar_step(count, total_angle);
+
+                (1..count)
+                    .map(|i| {
+                        let rotation = rotate_about(centre, step * i as f64);
+
+                        if rotate_copies {
+                            rotation
+                        } else {
+                            let moved = rotation.transform_point(reference);
+                            let offset = moved - reference;
+                            Transform2D::create_translation(offset.x, offset.y)
+                        }
+                    })
+                    .collect()
+            },
+        }
+    }
+}
+
+impl<S> Copy for ArrayPattern<S> {}
+
+impl<S> Clone for ArrayPattern<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+/// The angle between consecutive items in a polar array.
+fn polar_step(count: usize, total_angle: Angle) -> Angle {
+    let full_circle = total_angle.radians.abs() >= Angle::two_pi().radians;
+
+    let gaps = if full_circle {
+        count
+    } else {
+        count.saturating_sub(1)
+    };
+
+    if gaps == 0 {
+        Angle::zero()
+    } else {
+        total_angle / gaps as f64
+    }
+}
+
+/// An anti-clockwise rotation about some point.
+fn rotate_about<S>(
+    centre: Point2D<f64, S>,
+    angle: Angle,
+) -> Transform2D<f64, S, S> {
+    TransformBuilder::new().rotate_about(centre, angle).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::{approxeq::ApproxEq, UnknownUnit};
+
+    type Point = euclid::default::Point2D<f64>;
+    type Vector = euclid::default::Vector2D<f64>;
+
+    #[test]
+    fn rectangular_array() {
+        let pattern: ArrayPattern<UnknownUnit> = ArrayPattern::Rectangular {
+            rows: 2,
+            cols: 3,
+            row_spacing: Vector::new(0.0, 5.0),
+            col_spacing: Vector::new(10.0, 0.0),
+        };
+        let expected = vec![
+            Point::new(10.0, 0.0),
+            Point::new(20.0, 0.0),
+            Point::new(0.0, 5.0),
+            Point::new(10.0, 5.0),
+            Point::new(20.0, 5.0),
+        ];
+
+        let got: Vec<_> = pattern
+            .transforms(Point::zero())
+            .into_iter()
+            .map(|t| t.transform_point(Point::zero()))
+            .collect();
+
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn polar_array_around_a_full_circle() {
+        let pattern = ArrayPattern::Polar {
+            count: 4,
+            centre: Point::new(1.0, 1.0),
+            total_angle: Angle::two_pi(),
+            rotate_copies: true,
+        };
+        let original = Point::new(2.0, 1.0);
+        let expected = vec![
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 0.0),
+        ];
+
+        let got = pattern.transforms(original);
+
+        assert_eq!(got.len(), expected.len());
+        for (transform, expected) in got.into_iter().zip(expected) {
+            let direction = transform.transform_vector(Vector::new(1.0, 0.0));
+            assert!(transform.transform_point(original).approx_eq(&expected));
+            // the copies are rotated to face away from the centre
+            assert!(direction
+                .approx_eq(&(expected - Point::new(1.0, 1.0)).normalize()));
+        }
+    }
+
+    #[test]
+    fn polar_array_over_a_partial_arc_without_rotating() {
+        let pattern = ArrayPattern::Polar {
+            count: 3,
+            centre: Point::zero(),
+            total_angle: Angle::pi(),
+            rotate_copies: false,
+        };
+        let reference = Point::new(1.0, 0.0);
+        let expected = vec![Point::new(0.0, 1.0), Point::new(-1.0, 0.0)];
+
+        let got = pattern.transforms(reference);
+
+        assert_eq!(got.len(), expected.len());
+        for (transform, expected) in got.into_iter().zip(expected) {
+            let direction = transform.transform_vector(Vector::new(1.0, 0.0));
+            assert!(transform.transform_point(reference).approx_eq(&expected));
+            assert_eq!(direction, Vector::new(1.0, 0.0));
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{
+    algorithms::Segment,
+    normalize_angle,
+    primitives::{Arc, Line},
+};
+use euclid::{Length, Point2D, Vector2D};
+
+#[allow(unused_imports)] // rustdoc links
+use crate::algorithms::simplify;
+
+/// Smooth a polyline into a chain of tangent-continuous arcs.
+///
+/// Each pair of neighbouring points is joined by a [*biarc*][wiki], two arcs
+/// which meet smoothly and match the curve's estimated tangent at either end.
+/// Every piece starts and ends exactly on one of the input points.
+///
+/// Arcs which would bulge less than `tolerance` away from a straight line are
+/// emitted as [`Segment::Line`]s instead, so straight runs stay straight.
+///
+/// # Note
+///
+/// Tangents are estimated from neighbouring points, so noisy input will give
+/// wobbly output. You will normally want to [`simplify()`] a hand-drawn stroke
+/// before fitting biarcs to it.
+///
+/// [wiki]: https://en.wikipedia.org/wiki/Biarc
+pub fn fit_biarcs<S>(
+    points: &[Point2D<f64, S>],
+    tolerance: Length<f64, S>,
+) -> Vec<Segment<S>> {
+    let mut points = points.to_vec();
+    points.dedup();
+
+    if points.len() < 2 {
+        return Vec::new();
+    }
+
+    let tangents = estimate_tangents(&points);
+    let mut segments = Vec::new();
+
+    for i in 0..points.len() - 1 {
+        let (start, end) = (points[i], points[i + 1]);
+        let (start_tangent, end_tangent) = (tangents[i], tangents[i + 1]);
+        let joint = biarc_joint(start, start_tangent, end, end_tangent);
+
+        segments.push(arc_from_tangent(start, start_tangent, joint, tolerance));
+        // the second arc is the first one done backwards
+        segments.push(reversed(arc_from_tangent(
+            end,
+            -end_tangent,
+            joint,
+            tolerance,
+        )));
+    }
+
+    segments
+}
+
+/// Estimate the unit tangent at each point using its neighbours.
+///
+/// Where the stroke doubles back on itself the neighbours cancel out, so we
+/// fall back to the direction of the next (or previous) point.
+fn estimate_tangents<S>(points: &[Point2D<f64, S>]) -> Vec<Vector2D<f64, S>> {
+    let last = points.len() - 1;
+
+    (0..points.len())
+        .map(|i| {
+            let before = points[i.saturating_sub(1)];
+            let after = points[usize::min(i + 1, last)];
+            let mut direction = after - before;
+
+            if direction.square_length() < f64::EPSILON {
+                direction = if i < last {
+                    after - points[i]
+                } else {
+                    points[i] - before
+                };
+            }
+
+            direction.normalize()
+        })
+        .collect()
+}
+
+/// Find the point where the two halves of a biarc meet.
+///
+/// This uses the "equal chord" construction, where the control distance `d`
+/// is the same from both ends.
+fn biarc_joint<S>(
+    start: Point2D<f64, S>,
+    start_tangent: Vector2D<f64, S>,
+    end: Point2D<f64, S>,
+    end_tangent: Vector2D<f64, S>,
+) -> Point2D<f64, S> {
+    let v = end - start;
+    let t = start_tangent + end_tangent;
+    let denominator = 2.0 * (1.0 - start_tangent.dot(end_tangent));
+
+    // solve |v - d*t|² = (2d)² for the positive root
+    let d = if denominator.abs() < f64::EPSILON {
+        // the tangents are parallel, so the quadratic becomes linear
+        let v_dot_t = v.dot(end_tangent);
+
+        if v_dot_t.abs() < f64::EPSILON {
+            // a half-circle either side, meeting in the middle
+            return start.lerp(end, 0.5);
+        }
+
+        v.square_length() / (4.0 * v_dot_t)
+    } else {
+        let v_dot_t = v.dot(t);
+        (-v_dot_t
+            + (v_dot_t * v_dot_t + denominator * v.square_length()).sqrt())
+            / denominator
+    };
+
+    let first_control = start + start_tangent * d;
+    let second_control = end - end_tangent * d;
+
+    first_control.lerp(second_control, 0.5)
+}
+
+/// Create the arc leaving `start` in the direction of `tangent` and ending at
+/// `end`, or a straight line if it would be flatter than `tolerance`.
+fn arc_from_tangent<S>(
+    start: Point2D<f64, S>,
+    tangent: Vector2D<f64, S>,
+    end: Point2D<f64, S>,
+    tolerance: Length<f64, S>,
+) -> Segment<S> {
+    let chord = end - start;
+    let cross = tangent.cross(chord);
+
+    if cross.abs() < f64::EPSILON {
+        return Segment::Line(Line::new(start, end));
+    }
+
+    // a positive radius means the centre is to the left of the tangent
+    let signed_radius = chord.square_length() / (2.0 * cross);
+    let centre = start + Vector2D::new(-tangent.y, tangent.x) * signed_radius;
+    let radius = signed_radius.abs();
+
+    if !radius.is_finite() || radius <= 0.0 {
+        return Segment::Line(Line::new(start, end));
+    }
+
+    let start_angle = Line::new(centre, start).angle();
+    let end_angle = Line::new(centre, end).angle();
+    let sweep = if signed_radius > 0.0 {
+        normalize_angle(end_angle - start_angle)
+    } else {
+        -normalize_angle(start_angle - end_angle)
+    };
+    let arc = Arc::from_centre_radius(centre, radius, start_angle, sweep);
+
+    if arc.sagitta() <= tolerance.get() {
+        Segment::Line(Line::new(start, end))
+    } else {
+        Segment::Arc(arc)
+    }
+}
+
+fn reversed<S>(segment: Segment<S>) -> Segment<S> {
+    match segment {
+        Segment::Line(line) => Segment::Line(Line::new(line.end, line.start)),
+        Segment::Arc(arc) => Segment::Arc(arc.reverse()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    fn endpoints(segment: &Segment<euclid::UnknownUnit>) -> (Point, Point) {
+        match segment {
+            Segment::Line(line) => (line.start, line.end),
+            Segment::Arc(arc) => (arc.start(), arc.end()),
+        }
+    }
+
+    #[test]
+    fn too_few_points() {
+        assert!(
+            fit_biarcs::<euclid::UnknownUnit>(&[], Length::new(0.1)).is_empty()
+        );
+        assert!(fit_biarcs(&[Point::zero()], Length::new(0.1)).is_empty());
+    }
+
+    #[test]
+    fn straight_lines_stay_straight() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+
+        let got = fit_biarcs(&points, Length::new(0.01));
+
+        assert_eq!(got.len(), 4);
+        assert!(got.iter().all(|s| matches!(s, Segment::Line(_))));
+    }
+
+    #[test]
+    fn pieces_join_up_and_pass_through_every_point() {
+        let points: Vec<_> = (0..=8)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 8.0;
+                Point::new(10.0 * angle.cos(), 10.0 * angle.sin())
+            })
+            .collect();
+
+        let got = fit_biarcs(&points, Length::new(0.001));
+
+        assert_eq!(got.len(), 2 * (points.len() - 1));
+        for (i, pair) in got.chunks(2).enumerate() {
+            let (first_start, first_end) = endpoints(&pair[0]);
+            let (second_start, second_end) = endpoints(&pair[1]);
+
+            assert!(first_start.approx_eq(&points[i]));
+            assert!(first_end.approx_eq(&second_start));
+            assert!(second_end.approx_eq(&points[i + 1]));
+        }
+    }
+
+    #[test]
+    fn a_stroke_which_doubles_back() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 0.0),
+        ];
+
+        let got = fit_biarcs(&points, Length::new(0.01));
+
+        assert_eq!(got.len(), 4);
+        for segment in &got {
+            let (start, end) = endpoints(segment);
+            assert!(
+                start.x.is_finite()
+                    && start.y.is_finite()
+                    && end.x.is_finite()
+                    && end.y.is_finite(),
+                "{:?}",
+                segment
+            );
+        }
+        assert!(endpoints(&got[0]).0.approx_eq(&points[0]));
+        assert!(endpoints(&got[3]).1.approx_eq(&points[2]));
+    }
+
+    #[test]
+    fn points_on_a_circle_give_arcs_on_that_circle() {
+        let points: Vec<_> = (0..=8)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 8.0;
+                Point::new(10.0 * angle.cos(), 10.0 * angle.sin())
+            })
+            .collect();
+
+        let got = fit_biarcs(&points, Length::new(0.001));
+
+        // the estimated tangents are exact everywhere except the ends
+        for segment in &got[2..got.len() - 2] {
+            match segment {
+                Segment::Arc(arc) => {
+                    assert!(arc.centre().approx_eq(&Point::zero()));
+                    assert!(arc.radius().approx_eq(&10.0));
+                    assert!(arc.is_anticlockwise());
+                },
+                other => panic!("Expected an arc, found {:?}", other),
+            }
+        }
+    }
+}
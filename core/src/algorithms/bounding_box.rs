@@ -1,5 +1,5 @@
 use crate::{
-    primitives::{Arc, Line},
+    primitives::{Arc, Ellipse, EllipticalArc, InterpolatedSpline, Line},
     BoundingBox,
 };
 use euclid::{Angle, Point2D};
@@ -10,7 +10,7 @@ pub trait Bounded<S> {
     fn bounding_box(&self) -> BoundingBox<S>;
 }
 
-impl<'a, S, B: Bounded<S> + ?Sized> Bounded<S> for &'a B {
+impl<S, B: Bounded<S> + ?Sized> Bounded<S> for &B {
     fn bounding_box(&self) -> BoundingBox<S> { (*self).bounding_box() }
 }
 
@@ -33,33 +33,86 @@ impl<S> Bounded<S> for Arc<S> {
         let (x, y) = self.centre().to_tuple();
         let r = self.radius();
 
-        let mut bounds = BoundingBox::new(self.start(), self.end());
-
-        if self.contains_angle(Angle::zero()) {
-            let right = Point2D::new(x + r, y);
-            bounds = BoundingBox::new(bounds.bottom_left(), right);
-        }
-        if self.contains_angle(Angle::frac_pi_2()) {
-            let top = Point2D::new(x, y + r);
-            bounds = BoundingBox::new(bounds.bottom_left(), top);
-        }
-        if self.contains_angle(Angle::pi()) {
-            let left = Point2D::new(x - r, y);
-            bounds = BoundingBox::new(bounds.top_right(), left);
-        }
-        if self.contains_angle(Angle::pi() + Angle::frac_pi_2()) {
-            let bottom = Point2D::new(x, y - r);
-            bounds = BoundingBox::new(bounds.top_right(), bottom);
-        }
-
-        bounds
+        // the arc can only reach past its end points at the extremes of the
+        // circle, so we only need to include the extremes the arc passes
+        // through
+        let extremes = [
+            (Angle::zero(), Point2D::new(x + r, y)),
+            (Angle::frac_pi_2(), Point2D::new(x, y + r)),
+            (Angle::pi(), Point2D::new(x - r, y)),
+            (Angle::pi() + Angle::frac_pi_2(), Point2D::new(x, y - r)),
+        ];
+        let extremes = extremes
+            .iter()
+            .filter(|(angle, _)| self.contains_angle(*angle))
+            .map(|(_, point)| *point);
+
+        BoundingBox::around(
+            vec![self.start(), self.end()].into_iter().chain(extremes),
+        )
+        .expect("There are always at least 2 points")
+    }
+}
+
+impl<S> Bounded<S> for EllipticalArc<S> {
+    fn bounding_box(&self) -> BoundingBox<S> {
+        let ellipse = self.ellipse();
+        let (sin, cos) = ellipse.rotation().sin_cos();
+        let a = ellipse.major_radius();
+        let b = ellipse.minor_radius();
+
+        // like with an Arc, we only need the end points and whichever of the
+        // ellipse's left/right/top/bottom-most points the arc passes through.
+        // These are where dx/dt or dy/dt is zero.
+        let horizontal = Angle::radians(f64::atan2(-b * sin, a * cos));
+        let vertical = Angle::radians(f64::atan2(b * cos, a * sin));
+        let extremes = [
+            horizontal,
+            horizontal + Angle::pi(),
+            vertical,
+            vertical + Angle::pi(),
+        ];
+        let extremes = extremes
+            .iter()
+            .filter(|angle| self.contains_angle(**angle))
+            .map(|angle| ellipse.point_at(*angle));
+
+        BoundingBox::around(
+            vec![self.start(), self.end()].into_iter().chain(extremes),
+        )
+        .expect("There are always at least 2 points")
+    }
+}
+
+impl<S> Bounded<S> for Ellipse<S> {
+    fn bounding_box(&self) -> BoundingBox<S> {
+        EllipticalArc::full(*self).bounding_box()
+    }
+}
+
+impl<S> Bounded<S> for InterpolatedSpline<S> {
+    fn bounding_box(&self) -> BoundingBox<S> {
+        BoundingBox::around(self.extremes())
+            .expect("A spline always has at least 1 point")
     }
 }
 
+/// Get the [`BoundingBox`] around a slice of [`Bounded`] items, or `None` if
+/// the slice is empty.
+///
+/// This is a convenience wrapper around [`BoundingBox::around()`] which saves
+/// you from needing to turn a borrowed slice into an iterator.
+pub fn bounds_of<S, B: Bounded<S>>(items: &[B]) -> Option<BoundingBox<S>> {
+    BoundingBox::around(items)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use euclid::default::{Length, Point2D};
+    use euclid::{
+        approxeq::ApproxEq,
+        default::{Length, Point2D},
+    };
 
     #[test]
     fn bounding_box_around_line() {
@@ -74,4 +127,143 @@ mod tests {
         assert_eq!(bounds.bottom_left(), start);
         assert_eq!(bounds.top_right(), end);
     }
+
+    #[test]
+    fn bounding_box_around_semicircle() {
+        let arc = Arc::from_centre_radius(
+            Point2D::new(1.0, 1.0),
+            2.0,
+            Angle::zero(),
+            Angle::pi(),
+        );
+
+        let bounds = arc.bounding_box();
+
+        assert!(bounds.bottom_left().approx_eq(&Point2D::new(-1.0, 1.0)));
+        assert!(bounds.top_right().approx_eq(&Point2D::new(3.0, 3.0)));
+    }
+
+    #[test]
+    fn bounding_box_around_quarter_arc() {
+        let arc = Arc::from_centre_radius(
+            Point2D::zero(),
+            1.0,
+            Angle::frac_pi_4(),
+            Angle::frac_pi_2(),
+        );
+        let half_root_2 = std::f64::consts::FRAC_1_SQRT_2;
+
+        let bounds = arc.bounding_box();
+
+        let expected_bottom_left = Point2D::new(-half_root_2, half_root_2);
+        assert!(bounds.bottom_left().approx_eq(&expected_bottom_left));
+        assert!(bounds
+            .top_right()
+            .approx_eq(&Point2D::new(half_root_2, 1.0)));
+    }
+
+    #[test]
+    fn bounding_box_around_quarter_arc_crossing_the_x_axis() {
+        let arc = Arc::from_centre_radius(
+            Point2D::zero(),
+            1.0,
+            Angle::degrees(315.0),
+            Angle::frac_pi_2(),
+        );
+        let half_root_2 = std::f64::consts::FRAC_1_SQRT_2;
+
+        let bounds = arc.bounding_box();
+
+        let expected_bottom_left = Point2D::new(half_root_2, -half_root_2);
+        assert!(bounds.bottom_left().approx_eq(&expected_bottom_left));
+        assert!(bounds
+            .top_right()
+            .approx_eq(&Point2D::new(1.0, half_root_2)));
+    }
+
+    #[test]
+    fn small_arcs_have_small_bounds() {
+        let arc = Arc::from_centre_radius(
+            Point2D::zero(),
+            10.0,
+            Angle::degrees(40.0),
+            Angle::degrees(10.0),
+        );
+
+        let bounds = arc.bounding_box();
+
+        assert!(bounds.width().get() < 2.0);
+        assert!(bounds.height().get() < 2.0);
+    }
+
+    #[test]
+    fn bounds_of_a_slice() {
+        let lines = [
+            Line::new(Point2D::new(1.0, 1.0), Point2D::new(2.0, 3.0)),
+            Line::new(Point2D::new(-1.0, 0.0), Point2D::new(0.0, 0.5)),
+        ];
+
+        let got = bounds_of(&lines).unwrap();
+
+        assert_eq!(got.bottom_left(), Point2D::new(-1.0, 0.0));
+        assert_eq!(got.top_right(), Point2D::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn bounds_of_an_empty_slice() {
+        let lines: [Line<euclid::UnknownUnit>; 0] = [];
+
+        assert!(bounds_of(&lines).is_none());
+    }
+
+    #[test]
+    fn bounding_box_around_rotated_ellipse() {
+        let ellipse = Ellipse::new(
+            Point2D::new(1.0, 2.0),
+            2.0,
+            1.0,
+            Angle::degrees(90.0),
+        );
+
+        let got = ellipse.bounding_box();
+
+        assert!(got.bottom_left().approx_eq(&Point2D::new(0.0, 0.0)));
+        assert!(got.top_right().approx_eq(&Point2D::new(2.0, 4.0)));
+    }
+
+    #[test]
+    fn bounding_box_around_half_an_ellipse() {
+        let ellipse = Ellipse::new(Point2D::zero(), 2.0, 1.0, Angle::zero());
+        let top_half = EllipticalArc::new(
+            ellipse,
+            Angle::zero(),
+            Angle::frac_pi_2() * 2.0,
+        );
+
+        let got = top_half.bounding_box();
+
+        assert!(got.bottom_left().approx_eq(&Point2D::new(-2.0, 0.0)));
+        assert!(got.top_right().approx_eq(&Point2D::new(2.0, 1.0)));
+    }
+
+    #[test]
+    fn bounding_box_around_a_spline_includes_its_bulges() {
+        let points = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(3.0, 2.0),
+            Point2D::new(4.0, 0.0),
+        ];
+        let spline = InterpolatedSpline::catmull_rom(&points, 0.5).unwrap();
+
+        let bounds = spline.bounding_box();
+
+        let samples: Vec<_> = (0..=1000)
+            .map(|i| spline.point_at(i as f64 / 1000.0))
+            .collect();
+        let sampled = BoundingBox::around(samples).unwrap();
+        // the curve overshoots the control points between (1, 2) and (3, 2)
+        assert!(bounds.top_right().y > 2.0);
+        assert!(bounds.approx_eq_eps(&sampled, &1e-4));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{
     algorithms::Length,
-    primitives::{Arc, Line},
+    primitives::{Arc, EllipticalArc, Line},
+    Angle,
 };
 use euclid::{approxeq::ApproxEq, Point2D, Scale, Vector2D};
 use std::iter::FromIterator;
@@ -73,7 +74,7 @@ pub trait ClosestPoint<Space> {
     fn closest_point(&self, target: Point2D<f64, Space>) -> Closest<Space>;
 }
 
-impl<'c, Space, C: ClosestPoint<Space> + ?Sized> ClosestPoint<Space> for &'c C {
+impl<Space, C: ClosestPoint<Space> + ?Sized> ClosestPoint<Space> for &C {
     fn closest_point(&self, target: Point2D<f64, Space>) -> Closest<Space> {
         (*self).closest_point(target)
     }
@@ -125,8 +126,8 @@ impl<Space> ClosestPoint<Space> for Arc<Space> {
             return Closest::One(ideal_closest_point);
         }
 
-        let to_start = (self.start() - ideal_closest_point).length();
-        let to_end = (self.end() - ideal_closest_point).length();
+        let to_start = self.start().distance_to(ideal_closest_point);
+        let to_end = self.end().distance_to(ideal_closest_point);
 
         if to_start.approx_eq(&to_end) {
             Closest::Many(vec![self.start(), self.end()])
@@ -138,6 +139,64 @@ impl<Space> ClosestPoint<Space> for Arc<Space> {
     }
 }
 
+impl<Space> ClosestPoint<Space> for EllipticalArc<Space> {
+    /// Find the closest point numerically.
+    ///
+    /// There's no neat closed form for the closest point on an ellipse, so we
+    /// sample the arc to find a rough starting point then polish it with a
+    /// few rounds of Newton's method.
+    fn closest_point(&self, target: Point2D<f64, Space>) -> Closest<Space> {
+        /// How many samples to take over a full revolution.
+        const SAMPLES_PER_REVOLUTION: f64 = 64.0;
+        const NEWTON_ITERATIONS: usize = 16;
+
+        let ellipse = self.ellipse();
+
+        if ellipse.is_circle()
+            && target.distance_to(self.centre()).approx_eq(&0.0)
+        {
+            return Closest::Infinite;
+        }
+
+        let sweep = self.sweep_angle().radians;
+        let (low, high) = (f64::min(sweep, 0.0), f64::max(sweep, 0.0));
+        let distance =
+            |t: f64| self.point_at(Angle::radians(t)).distance_to(target);
+
+        let turns = sweep.abs() / Angle::two_pi().radians;
+        let samples = (turns * SAMPLES_PER_REVOLUTION).ceil().max(2.0) as usize;
+        let mut best = (0..=samples)
+            .map(|i| sweep * i as f64 / samples as f64)
+            .min_by(|&a, &b| distance(a).total_cmp(&distance(b)))
+            .expect("There is always at least one sample");
+
+        // minimise the squared distance by finding where its derivative,
+        // f(t) = (P(t) - target) · P'(t), is zero
+        for _ in 0..NEWTON_ITERATIONS {
+            let angle = self.start_angle() + Angle::radians(best);
+            let offset = ellipse.point_at(angle) - target;
+            let tangent = ellipse.tangent_at(angle);
+            // the second derivative of a point on an ellipse always points
+            // back at the centre
+            let second = self.centre() - ellipse.point_at(angle);
+
+            let f = offset.dot(tangent);
+            let f_prime = tangent.square_length() + offset.dot(second);
+            if f_prime.abs() <= f64::EPSILON {
+                break;
+            }
+
+            let next = (best - f / f_prime).max(low).min(high);
+            if (next - best).abs() <= f64::EPSILON {
+                break;
+            }
+            best = next;
+        }
+
+        Closest::One(self.point_at(Angle::radians(best)))
+    }
+}
+
 /// An enum containing the different possible solutions for
 /// [`ClosestPoint::closest_point()`].
 #[derive(Debug, Clone, PartialEq)]
@@ -152,12 +211,7 @@ pub enum Closest<Space> {
 
 impl<Space> Closest<Space> {
     /// Are there infinitely many closest points?
-    pub fn is_infinite(&self) -> bool {
-        match self {
-            Closest::Infinite => true,
-            _ => false,
-        }
-    }
+    pub fn is_infinite(&self) -> bool { matches!(self, Closest::Infinite) }
 
     /// Get a slice of all the closest [`Point2D`]s.
     ///
@@ -168,9 +222,24 @@ impl<Space> Closest<Space> {
         match self {
             Closest::Infinite => &[],
             Closest::One(item) => std::slice::from_ref(item),
-            Closest::Many(items) => &items,
+            Closest::Many(items) => items,
         }
     }
+
+    /// How far is `target` from the nearest of these closest points?
+    ///
+    /// # Note
+    ///
+    /// This returns [`None`] for [`Closest::Infinite`]. That case usually
+    /// means `target` sits at the centre of an arc, where every point is the
+    /// same distance away. It carries no point to measure from, so the caller
+    /// has to pick a fallback for that case.
+    pub fn distance_to(&self, target: Point2D<f64, Space>) -> Option<f64> {
+        self.points()
+            .iter()
+            .map(|&p| p.distance_to(target))
+            .min_by(|a, b| a.total_cmp(b))
+    }
 }
 
 impl<Space> FromIterator<Point2D<f64, Space>> for Closest<Space> {
@@ -193,6 +262,7 @@ mod tests {
     use crate::Angle;
 
     type Point = euclid::default::Point2D<f64>;
+    type Vector = euclid::default::Vector2D<f64>;
 
     #[test]
     fn on_the_line() {
@@ -260,6 +330,17 @@ mod tests {
         let got = arc.closest_point(centre);
 
         assert_eq!(got, Closest::Infinite);
+        assert_eq!(got.distance_to(centre), None);
+    }
+
+    #[test]
+    fn distance_to_the_nearest_closest_point() {
+        let closest =
+            Closest::Many(vec![Point::new(3.0, 4.0), Point::new(0.0, 1.0)]);
+
+        let got = closest.distance_to(Point::zero());
+
+        assert_eq!(got, Some(1.0));
     }
 
     #[test]
@@ -294,4 +375,37 @@ mod tests {
 
         assert_eq!(got, Closest::Many(vec![arc.start(), arc.end()]));
     }
+
+    #[test]
+    fn closest_point_on_an_ellipse() {
+        use crate::primitives::Ellipse;
+
+        let ellipse = Ellipse::new(Point::zero(), 4.0, 2.0, Angle::zero());
+        let arc = EllipticalArc::full(ellipse);
+
+        // directly above the minor axis
+        let got = arc.closest_point(Point::new(0.0, 5.0));
+        assert!(got.points()[0].approx_eq(&Point::new(0.0, 2.0)));
+
+        // somewhere off-axis, the closest point is where the line to the
+        // target is perpendicular to the tangent
+        let target = Point::new(3.0, 3.0);
+        let got = arc.closest_point(target).points()[0];
+        let (x, y) = (got.x / 4.0, got.y / 2.0);
+        assert!((x * x + y * y - 1.0).abs() < 1e-9);
+        let tangent = Vector::new(-got.y * 4.0 / 2.0, got.x * 2.0 / 4.0);
+        assert!((target - got).dot(tangent).abs() < 1e-9);
+    }
+
+    #[test]
+    fn closest_point_past_the_end_of_an_elliptical_arc() {
+        use crate::primitives::Ellipse;
+
+        let ellipse = Ellipse::new(Point::zero(), 4.0, 2.0, Angle::zero());
+        let top_half = EllipticalArc::new(ellipse, Angle::zero(), Angle::pi());
+
+        let got = top_half.closest_point(Point::new(5.0, -3.0));
+
+        assert_eq!(got, Closest::One(top_half.start()));
+    }
 }
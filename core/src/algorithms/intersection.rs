@@ -0,0 +1,352 @@
+use crate::primitives::{Arc, Line};
+use arrayvec::ArrayVec;
+use euclid::{Point2D, Vector2D};
+
+/// How close the discriminant needs to be to zero (relative to the size of
+/// the problem) before we treat a line as tangent to a circle.
+const TANGENT_TOLERANCE: f64 = 1e-10;
+
+/// Find where two [`Line`]s cross.
+///
+/// Only an intersection lying on both line segments is returned. Parallel
+/// (including collinear) lines are treated as never intersecting.
+///
+/// # Examples
+///
+/// ```rust
+/// # use arcs_core::{algorithms::line_line_intersection, primitives::Line};
+/// # type Point = euclid::default::Point2D<f64>;
+/// let a = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+/// let b = Line::new(Point::new(0.0, 2.0), Point::new(2.0, 0.0));
+///
+/// let got = line_line_intersection(&a, &b);
+///
+/// assert_eq!(got, Some(Point::new(1.0, 1.0)));
+/// ```
+pub fn line_line_intersection<S>(
+    a: &Line<S>,
+    b: &Line<S>,
+) -> Option<Point2D<f64, S>> {
+    // solve a.start + t*r = b.start + u*s for t and u
+    let r = a.displacement();
+    let s = b.displacement();
+    let denominator = r.cross(s);
+
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let between_starts = b.start - a.start;
+    let t = between_starts.cross(s) / denominator;
+    let u = between_starts.cross(r) / denominator;
+
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(a.start + r * t)
+    } else {
+        None
+    }
+}
+
+/// Find the locations where a [`Line`] crosses an [`Arc`].
+///
+/// Only points which lie on both the line segment and the arc's angular sweep
+/// are returned, so there may be 0, 1 (e.g. when the line is tangent), or 2
+/// intersections.
+///
+/// # Examples
+///
+/// ```rust
+/// # use arcs_core::{algorithms::line_arc_intersections, primitives::{Arc, Line}, Angle};
+/// # type Point = euclid::default::Point2D<f64>;
+/// let arc = Arc::from_centre_radius(Point::zero(), 1.0, Angle::zero(), Angle::pi());
+/// let line = Line::new(Point::new(-2.0, 0.5), Point::new(2.0, 0.5));
+///
+/// let got = line_arc_intersections(&line, &arc);
+///
+/// assert_eq!(got.len(), 2);
+/// ```
+pub fn line_arc_intersections<S>(
+    line: &Line<S>,
+    arc: &Arc<S>,
+) -> ArrayVec<[Point2D<f64, S>; 2]> {
+    let mut intersections = ArrayVec::new();
+
+    // solve |start + t*d - centre|² = r² for t
+    let d = line.displacement();
+    let f = line.start - arc.centre();
+    let a = d.dot(d);
+    let b = 2.0 * f.dot(d);
+    let c = f.dot(f) - arc.radius() * arc.radius();
+
+    if a == 0.0 {
+        // the line is degenerate
+        return intersections;
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    let tolerance = TANGENT_TOLERANCE * a * arc.radius() * arc.radius();
+
+    let roots: ArrayVec<[f64; 2]> = if discriminant.abs() <= tolerance {
+        [-b / (2.0 * a)].iter().copied().collect()
+    } else if discriminant < 0.0 {
+        ArrayVec::new()
+    } else {
+        let root = discriminant.sqrt();
+        [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+            .iter()
+            .copied()
+            .collect()
+    };
+
+    for t in roots {
+        if (0.0..=1.0).contains(&t) {
+            let point = line.start + d * t;
+
+            if is_within_sweep(arc, point) {
+                intersections.push(point);
+            }
+        }
+    }
+
+    intersections
+}
+
+/// Find the locations where two [`Arc`]s cross.
+///
+/// Only points which lie within both arcs' angular sweeps are returned.
+/// Concentric arcs (including arcs on the same circle) either never meet or
+/// overlap along their length, so they are treated as having no
+/// intersections.
+///
+/// # Examples
+///
+/// ```rust
+/// # use arcs_core::{algorithms::arc_arc_intersections, primitives::Arc, Angle};
+/// # type Point = euclid::default::Point2D<f64>;
+/// let a = Arc::from_centre_radius(Point::zero(), 1.0, Angle::zero(), Angle::pi());
+/// let b = Arc::from_centre_radius(Point::new(1.0, 0.0), 1.0, Angle::zero(), Angle::pi());
+///
+/// let got = arc_arc_intersections(&a, &b);
+///
+/// assert_eq!(got.len(), 1);
+/// ```
+pub fn arc_arc_intersections<S>(
+    a: &Arc<S>,
+    b: &Arc<S>,
+) -> ArrayVec<[Point2D<f64, S>; 2]> {
+    let mut intersections = ArrayVec::new();
+
+    let between_centres = b.centre() - a.centre();
+    let distance = between_centres.length();
+    let (r_a, r_b) = (a.radius(), b.radius());
+
+    if distance == 0.0 || distance > r_a + r_b || distance < (r_a - r_b).abs() {
+        return intersections;
+    }
+
+    // the intersections are symmetric about the line joining both centres,
+    // so find where the chord crosses that line and go either side
+    let along =
+        (r_a * r_a - r_b * r_b + distance * distance) / (2.0 * distance);
+    let half_chord_squared = r_a * r_a - along * along;
+    let direction = between_centres / distance;
+    let midpoint = a.centre() + direction * along;
+
+    let candidates: ArrayVec<[Point2D<f64, S>; 2]> =
+        if half_chord_squared <= TANGENT_TOLERANCE * r_a * r_a {
+            [midpoint].iter().copied().collect()
+        } else {
+            let perpendicular = Vector2D::new(-direction.y, direction.x)
+                * half_chord_squared.sqrt();
+            [midpoint + perpendicular, midpoint - perpendicular]
+                .iter()
+                .copied()
+                .collect()
+        };
+
+    for point in candidates {
+        if is_within_sweep(a, point) && is_within_sweep(b, point) {
+            intersections.push(point);
+        }
+    }
+
+    intersections
+}
+
+/// Does the angle from an [`Arc`]'s centre to `point` fall within the arc's
+/// sweep?
+fn is_within_sweep<S>(arc: &Arc<S>, point: Point2D<f64, S>) -> bool {
+    // use the same angle calculation as Line::angle() because
+    // Vector2D::angle_from_x_axis() is only approximate
+    arc.contains_angle(Line::new(arc.centre(), point).angle())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Angle;
+    use euclid::approxeq::ApproxEq;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    fn unit_semicircle() -> Arc<euclid::UnknownUnit> {
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::zero(), Angle::pi())
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let a = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+        let b = Line::new(Point::new(1.0, -1.0), Point::new(1.0, 3.0));
+
+        let got = line_line_intersection(&a, &b).unwrap();
+
+        assert!(got.approx_eq(&Point::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn lines_which_would_cross_if_extended() {
+        let a = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+        let b = Line::new(Point::new(1.0, 1.0), Point::new(1.0, 3.0));
+
+        assert!(line_line_intersection(&a, &b).is_none());
+    }
+
+    #[test]
+    fn parallel_lines_never_cross() {
+        let a = Line::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+        let b = Line::new(Point::new(0.0, 1.0), Point::new(4.0, 1.0));
+
+        assert!(line_line_intersection(&a, &b).is_none());
+        assert!(line_line_intersection(&a, &a).is_none());
+    }
+
+    #[test]
+    fn line_through_a_chord() {
+        let line = Line::new(Point::new(-2.0, 0.0), Point::new(2.0, 0.0));
+
+        let got = line_arc_intersections(&line, &unit_semicircle());
+
+        assert_eq!(got.len(), 2);
+        assert!(got[0].approx_eq(&Point::new(-1.0, 0.0)));
+        assert!(got[1].approx_eq(&Point::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn tangent_line_touches_once() {
+        let line = Line::new(Point::new(-2.0, 1.0), Point::new(2.0, 1.0));
+
+        let got = line_arc_intersections(&line, &unit_semicircle());
+
+        assert_eq!(got.len(), 1);
+        assert!(got[0].approx_eq(&Point::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn line_which_misses() {
+        let line = Line::new(Point::new(-2.0, 3.0), Point::new(2.0, 3.0));
+
+        let got = line_arc_intersections(&line, &unit_semicircle());
+
+        assert!(got.is_empty());
+    }
+
+    #[test]
+    fn intersections_outside_the_sweep_are_ignored() {
+        let line = Line::new(Point::new(-2.0, -0.5), Point::new(2.0, -0.5));
+
+        let got = line_arc_intersections(&line, &unit_semicircle());
+
+        assert!(got.is_empty());
+    }
+
+    #[test]
+    fn intersections_past_the_end_of_the_segment_are_ignored() {
+        let line = Line::new(Point::new(0.0, 0.5), Point::new(2.0, 0.5));
+
+        let got = line_arc_intersections(&line, &unit_semicircle());
+
+        assert_eq!(got.len(), 1);
+        assert!(got[0].approx_eq(&Point::new(0.75_f64.sqrt(), 0.5)));
+    }
+
+    #[test]
+    fn overlapping_circles_cross_twice() {
+        let a = Arc::from_centre_radius(
+            Point::zero(),
+            1.0,
+            Angle::zero(),
+            Angle::two_pi(),
+        );
+        let b = Arc::from_centre_radius(
+            Point::new(1.0, 0.0),
+            1.0,
+            Angle::zero(),
+            Angle::two_pi(),
+        );
+
+        let got = arc_arc_intersections(&a, &b);
+
+        let y = 0.75_f64.sqrt();
+        assert_eq!(got.len(), 2);
+        assert!(got.iter().any(|p| p.approx_eq(&Point::new(0.5, y))));
+        assert!(got.iter().any(|p| p.approx_eq(&Point::new(0.5, -y))));
+    }
+
+    #[test]
+    fn intersections_must_be_on_both_sweeps() {
+        let a = unit_semicircle();
+        let b = Arc::from_centre_radius(
+            Point::new(1.0, 0.0),
+            1.0,
+            Angle::pi(),
+            Angle::frac_pi_2(),
+        );
+
+        let got = arc_arc_intersections(&a, &b);
+
+        assert!(got.is_empty());
+    }
+
+    #[test]
+    fn touching_circles_meet_once() {
+        let b = Arc::from_centre_radius(
+            Point::new(2.0, 0.0),
+            1.0,
+            Angle::frac_pi_2(),
+            Angle::pi(),
+        );
+
+        let got = arc_arc_intersections(&unit_semicircle(), &b);
+
+        assert_eq!(got.len(), 1);
+        assert!(got[0].approx_eq(&Point::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn concentric_and_identical_arcs_never_intersect() {
+        let a = unit_semicircle();
+        let smaller = Arc::from_centre_radius(
+            Point::zero(),
+            0.5,
+            Angle::zero(),
+            Angle::pi(),
+        );
+
+        assert!(arc_arc_intersections(&a, &a).is_empty());
+        assert!(arc_arc_intersections(&a, &smaller).is_empty());
+    }
+
+    #[test]
+    fn distant_arcs_never_intersect() {
+        let far_away = Arc::from_centre_radius(
+            Point::new(10.0, 0.0),
+            1.0,
+            Angle::zero(),
+            Angle::two_pi(),
+        );
+
+        let got = arc_arc_intersections(&unit_semicircle(), &far_away);
+
+        assert!(got.is_empty());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::primitives::{Arc, Line};
+use crate::{
+    primitives::{Arc, EllipticalArc, Line},
+    Angle,
+};
 use euclid::Vector2D;
 
 /// Something which has a finite length.
@@ -7,7 +10,7 @@ pub trait Length {
     fn length(&self) -> f64;
 }
 
-impl<'a, L: Length + ?Sized> Length for &'a L {
+impl<L: Length + ?Sized> Length for &L {
     fn length(&self) -> f64 { (*self).length() }
 }
 
@@ -57,6 +60,38 @@ impl<Space> Length for Arc<Space> {
     fn length(&self) -> f64 { self.radius() * self.sweep_angle().radians.abs() }
 }
 
+impl<Space> Length for EllipticalArc<Space> {
+    /// Calculates the length of an [`EllipticalArc`].
+    ///
+    /// There is no closed form for the perimeter of an ellipse, so this
+    /// integrates numerically using Simpson's rule. The result is accurate to
+    /// well within any reasonable drawing tolerance.
+    fn length(&self) -> f64 {
+        /// How many intervals to use for a full revolution.
+        const INTERVALS_PER_REVOLUTION: f64 = 256.0;
+
+        let sweep = self.sweep_angle().radians;
+        let turns = sweep.abs() / Angle::two_pi().radians;
+        // Simpson's rule needs an even number of intervals
+        let intervals = (turns * INTERVALS_PER_REVOLUTION / 2.0).ceil().max(1.0)
+            as usize
+            * 2;
+        let step = sweep / intervals as f64;
+        let speed = |i: usize| {
+            let angle = self.start_angle() + Angle::radians(step * i as f64);
+            self.ellipse().tangent_at(angle).length()
+        };
+
+        let mut sum = speed(0) + speed(intervals);
+        for i in 1..intervals {
+            let weight = if i % 2 == 0 { 2.0 } else { 4.0 };
+            sum += weight * speed(i);
+        }
+
+        sum * step.abs() / 3.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +120,30 @@ mod tests {
             arc.sweep_angle().radians.abs() * arc.radius()
         );
     }
+
+    #[test]
+    fn elliptical_arc() {
+        use crate::primitives::Ellipse;
+        use euclid::approxeq::ApproxEq;
+
+        let circle = EllipticalArc::from(Arc::from_centre_radius(
+            Point::zero(),
+            10.0,
+            Angle::zero(),
+            Angle::pi(),
+        ));
+        assert!(circle.length().approx_eq(&(10.0 * Angle::pi().radians)));
+
+        // reference value from Ramanujan's second approximation, which is
+        // exact to ~1e-11 for an ellipse this round
+        let ellipse = Ellipse::new(Point::zero(), 5.0, 3.0, Angle::zero());
+        let h: f64 = (5.0 - 3.0) * (5.0 - 3.0) / ((5.0 + 3.0) * (5.0 + 3.0));
+        let ramanujan = Angle::pi().radians
+            * (5.0 + 3.0)
+            * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()));
+
+        let got = EllipticalArc::full(ellipse).length();
+
+        assert!((got - ramanujan).abs() < 1e-6, "{} != {}", got, ramanujan);
+    }
 }
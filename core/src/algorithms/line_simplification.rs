@@ -33,7 +33,7 @@ pub fn simplify<Space>(
     // push the first point
     buffer.push(points[0]);
     // then simplify every point in between the start and end
-    simplify_points(&points[..], tolerance, &mut buffer);
+    simplify_points(points, tolerance, &mut buffer);
     // and finally the last one
     buffer.push(*points.last().unwrap());
 
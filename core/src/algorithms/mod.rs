@@ -2,20 +2,34 @@
 
 mod affine_transform;
 mod approximate;
+mod array;
+mod biarc;
 mod bounding_box;
 mod closest_point;
+mod intersection;
 mod length;
 mod line_simplification;
 mod scale;
 mod scale_non_uniform;
+mod transform_builder;
 mod translate;
+mod trim;
 
 pub use affine_transform::AffineTransformable;
-pub use approximate::{Approximate, ApproximatedArc};
-pub use bounding_box::Bounded;
+pub use approximate::{
+    Approximate, ApproximatedArc, ApproximatedEllipticalArc,
+};
+pub use array::ArrayPattern;
+pub use biarc::fit_biarcs;
+pub use bounding_box::{bounds_of, Bounded};
 pub use closest_point::{Closest, ClosestPoint};
+pub use intersection::{
+    arc_arc_intersections, line_arc_intersections, line_line_intersection,
+};
 pub use length::Length;
 pub use line_simplification::simplify;
 pub use scale::Scale;
-pub use scale_non_uniform::ScaleNonUniform;
+pub use scale_non_uniform::{scale_arc_non_uniform, ScaleNonUniform};
+pub use transform_builder::TransformBuilder;
 pub use translate::Translate;
+pub use trim::{split_at_intersections, trim, Segment};
@@ -1,6 +1,24 @@
-use crate::{algorithms::ScaleNonUniform, primitives::Arc};
+use crate::algorithms::{ScaleNonUniform, Translate};
+use euclid::Point2D;
 
 /// Something who's dimensions can be scaled uniformly.
+///
+/// # Examples
+///
+/// Scaling normally happens about the origin, but you can also scale about
+/// an arbitrary pivot point.
+///
+/// ```rust
+/// use arcs_core::{primitives::Line, algorithms::Scale};
+/// # type Point = euclid::default::Point2D<f64>;
+///
+/// let original = Line::new(Point::new(1.0, 1.0), Point::new(3.0, 1.0));
+///
+/// let scaled = original.scaled_about(2.0, Point::new(2.0, 1.0));
+///
+/// assert_eq!(scaled.start, Point::new(0.0, 1.0));
+/// assert_eq!(scaled.end, Point::new(4.0, 1.0));
+/// ```
 pub trait Scale {
     /// Scale the object in-place.
     fn scale(&mut self, scale_factor: f64);
@@ -15,22 +33,40 @@ pub trait Scale {
 
         clone
     }
-}
 
-impl<S: ScaleNonUniform> Scale for S {
-    fn scale(&mut self, scale_factor: f64) {
-        self.scale_non_uniform(scale_factor, scale_factor);
+    /// Scale the object in-place, keeping `pivot` fixed instead of the
+    /// origin.
+    ///
+    /// The `pivot` is in the same space the object can be [`Translate`]d in.
+    /// By default this moves the `pivot` to the origin, scales, then moves it
+    /// back again.
+    fn scale_about<S>(&mut self, scale_factor: f64, pivot: Point2D<f64, S>)
+    where
+        Self: Translate<S>,
+    {
+        let offset = pivot.to_vector();
+
+        self.translate(-offset);
+        self.scale(scale_factor);
+        self.translate(offset);
+    }
+
+    /// Convenience method for getting a copy of this object scaled about
+    /// `pivot`.
+    fn scaled_about<S>(&self, scale_factor: f64, pivot: Point2D<f64, S>) -> Self
+    where
+        Self: Sized + Clone + Translate<S>,
+    {
+        let mut clone = self.clone();
+        clone.scale_about(scale_factor, pivot);
+
+        clone
     }
 }
 
-impl<Space> Scale for Arc<Space> {
+impl<S: ScaleNonUniform> Scale for S {
     fn scale(&mut self, scale_factor: f64) {
-        *self = Arc::from_centre_radius(
-            self.centre().scaled(scale_factor),
-            self.radius() * scale_factor,
-            self.start_angle(),
-            self.sweep_angle(),
-        );
+        self.scale_non_uniform(scale_factor, scale_factor);
     }
 }
 
@@ -38,11 +74,11 @@ impl<Space> Scale for Arc<Space> {
 mod tests {
     use super::*;
     use crate::{
-        algorithms::{AffineTransformable, Translate},
+        algorithms::{AffineTransformable, TransformBuilder, Translate},
         primitives::{Arc, Line},
         BoundingBox,
     };
-    use euclid::Angle;
+    use euclid::{approxeq::ApproxEq, Angle};
 
     pub type Vector = euclid::default::Vector2D<f64>;
     pub type Transform = euclid::default::Transform2D<f64>;
@@ -100,6 +136,14 @@ mod tests {
         let transformed = original.transformed(combined_transform);
 
         assert_eq!(transformed, expected);
+
+        // Or use a `TransformBuilder`, which applies each step in the order
+        // it was written
+        let built = TransformBuilder::new()
+            .scale_about(mid_point.to_point(), scale_factor, scale_factor)
+            .build();
+
+        assert!(original.transformed(built).approx_eq(&expected));
     }
 
     #[test]
@@ -150,6 +194,32 @@ mod tests {
         transformed.translate(centre.to_vector());
 
         assert_eq!(transformed, expected);
+
+        // which is exactly what scaling about the centre does
+        let transformed = original.scaled_about(scale_factor, centre);
+
+        assert_eq!(transformed, expected);
+    }
+
+    #[test]
+    fn scale_about_only_needs_scale_and_translate() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Dot(Point);
+
+        impl Scale for Dot {
+            fn scale(&mut self, scale_factor: f64) { self.0 *= scale_factor; }
+        }
+
+        impl Translate<euclid::UnknownUnit> for Dot {
+            fn translate(&mut self, displacement: Vector) {
+                self.0 += displacement;
+            }
+        }
+
+        let got =
+            Dot(Point::new(3.0, 1.0)).scaled_about(2.0, Point::new(1.0, 1.0));
+
+        assert_eq!(got, Dot(Point::new(5.0, 1.0)));
     }
 
     #[test]
@@ -1,5 +1,11 @@
-use crate::{algorithms::AffineTransformable, BoundingBox};
-use euclid::Transform2D;
+use crate::{
+    algorithms::{AffineTransformable, Approximate, Translate},
+    primitives::Arc,
+};
+
+#[allow(unused_imports)] // rustdoc links
+use crate::primitives::EllipticalArc;
+use euclid::{Point2D, Transform2D};
 
 /// Something who's dimensions can be scaled independently (the *non-uniform*
 /// bit) in the x and y directions.
@@ -17,6 +23,16 @@ use euclid::Transform2D;
 /// assert_eq!(scaled.start, Point::zero());
 /// assert_eq!(scaled.end, Point::new(20.0, -5.0));
 /// ```
+///
+/// # Arcs
+///
+/// Scaling an [`Arc`] by different amounts in each direction gives an
+/// ellipse, which can't be represented by an [`Arc`]. The implementation
+/// falls back to scaling the radius by the geometric mean of the scale factors
+/// (`√|det|`), so the result will be distorted. Convert it to an
+/// [`EllipticalArc`] first to scale it exactly, or use
+/// [`scale_arc_non_uniform()`] to get a polyline which follows the true
+/// shape.
 pub trait ScaleNonUniform {
     /// Scale the object in-place.
     fn scale_non_uniform(&mut self, factor_x: f64, factor_y: f64);
@@ -31,6 +47,44 @@ pub trait ScaleNonUniform {
 
         clone
     }
+
+    /// Scale the object in-place, keeping `pivot` fixed instead of the
+    /// origin.
+    ///
+    /// The `pivot` is in the same space the object can be [`Translate`]d in.
+    /// By default this moves the `pivot` to the origin, scales, then moves it
+    /// back again.
+    fn scale_non_uniform_about<S>(
+        &mut self,
+        factor_x: f64,
+        factor_y: f64,
+        pivot: Point2D<f64, S>,
+    ) where
+        Self: Translate<S>,
+    {
+        let offset = pivot.to_vector();
+
+        self.translate(-offset);
+        self.scale_non_uniform(factor_x, factor_y);
+        self.translate(offset);
+    }
+
+    /// Convenience method for getting a copy of this object scaled about
+    /// `pivot`.
+    fn scaled_non_uniform_about<S>(
+        &self,
+        factor_x: f64,
+        factor_y: f64,
+        pivot: Point2D<f64, S>,
+    ) -> Self
+    where
+        Self: Sized + Clone + Translate<S>,
+    {
+        let mut clone = self.clone();
+        clone.scale_non_uniform_about(factor_x, factor_y, pivot);
+
+        clone
+    }
 }
 
 impl<A: AffineTransformable> ScaleNonUniform for A {
@@ -39,20 +93,36 @@ impl<A: AffineTransformable> ScaleNonUniform for A {
     }
 }
 
-impl<Space> ScaleNonUniform for BoundingBox<Space> {
-    fn scale_non_uniform(&mut self, factor_x: f64, factor_y: f64) {
-        let bottom_left =
-            self.bottom_left().scaled_non_uniform(factor_x, factor_y);
-        let top_right = self.top_right().scaled_non_uniform(factor_x, factor_y);
-
-        *self = BoundingBox::new(bottom_left, top_right);
-    }
+/// Scale an [`Arc`] non-uniformly, approximating the resulting elliptical arc
+/// with a polyline.
+///
+/// Every point on the polyline will be within `tolerance` of the true ellipse.
+/// The first and last points are the [`Arc`]'s start and end, scaled.
+pub fn scale_arc_non_uniform<S>(
+    arc: &Arc<S>,
+    factor_x: f64,
+    factor_y: f64,
+    tolerance: f64,
+) -> Vec<Point2D<f64, S>> {
+    // scaling stretches the error by at most the largest factor, so
+    // approximate the original arc more finely to compensate
+    let largest_factor = f64::max(factor_x.abs(), factor_y.abs());
+    let tolerance = if largest_factor > 0.0 {
+        tolerance / largest_factor
+    } else {
+        tolerance
+    };
+
+    arc.approximate(tolerance)
+        .map(|point| point.scaled_non_uniform(factor_x, factor_y))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{algorithms::Translate, primitives::Line};
+    use crate::{algorithms::Translate, primitives::Line, Angle};
+    use euclid::approxeq::ApproxEq;
 
     type Point = euclid::default::Point2D<f64>;
     type Vector = euclid::default::Vector2D<f64>;
@@ -98,6 +168,15 @@ mod tests {
         let transformed = original.transformed(combined_transform);
 
         assert_eq!(transformed, expected);
+
+        // Or let `scale_non_uniform_about()` do it for us
+        let transformed = original.scaled_non_uniform_about(
+            factor_x,
+            factor_y,
+            base.to_point(),
+        );
+
+        assert_eq!(transformed, expected);
     }
 
     #[test]
@@ -134,5 +213,43 @@ mod tests {
         transformed.translate(mid_point);
 
         assert_eq!(transformed, expected);
+
+        let transformed = original.scaled_non_uniform_about(
+            factor_x,
+            factor_y,
+            mid_point.to_point(),
+        );
+
+        assert_eq!(transformed, expected);
+    }
+
+    #[test]
+    fn non_uniformly_scaled_arc_follows_the_ellipse() {
+        let arc = Arc::from_centre_radius(
+            Point::zero(),
+            1.0,
+            Angle::zero(),
+            Angle::pi(),
+        );
+        let tolerance = 0.01;
+
+        let got = scale_arc_non_uniform(&arc, 2.0, 0.5, tolerance);
+
+        assert!(got.len() > 2);
+        assert!(got[0].approx_eq(&Point::new(2.0, 0.0)));
+        assert!(got[got.len() - 1].approx_eq(&Point::new(-2.0, 0.0)));
+
+        for point in &got {
+            let on_ellipse = (point.x / 2.0).powi(2) + (point.y / 0.5).powi(2);
+            assert!((on_ellipse - 1.0).abs() < 1e-10, "{:?}", point);
+        }
+
+        // make sure the mid-point of each chord is close enough to the ellipse
+        for window in got.windows(2) {
+            let mid = window[0].lerp(window[1], 0.5);
+            let angle = f64::atan2(mid.y / 0.5, mid.x / 2.0);
+            let on_ellipse = Point::new(2.0 * angle.cos(), 0.5 * angle.sin());
+            assert!(mid.distance_to(on_ellipse) <= tolerance);
+        }
     }
 }
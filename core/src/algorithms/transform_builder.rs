@@ -0,0 +1,183 @@
+use crate::{primitives::Line, Angle};
+use euclid::{Point2D, Transform2D, Vector2D};
+
+/// Build up a [`Transform2D`] one step at a time, where each step is applied
+/// *after* the ones before it.
+///
+/// This avoids needing to remember which order euclid's `pre_*` and `post_*`
+/// methods compose in, and rotations are anti-clockwise like everywhere else
+/// in this crate (euclid's go clockwise).
+///
+/// # Examples
+///
+/// ```rust
+/// use arcs_core::{algorithms::TransformBuilder, Angle};
+/// use euclid::{approxeq::ApproxEq, default::{Point2D, Vector2D}};
+///
+/// let transform = TransformBuilder::new()
+///     .translate(Vector2D::new(1.0, 0.0)) // first move the point right
+///     .rotate(Angle::degrees(90.0)) // then rotate it a quarter turn
+///     .scale(2.0, 2.0) // then make everything twice as big
+///     .build();
+///
+/// let got = transform.transform_point(Point2D::new(1.0, 0.0));
+///
+/// assert!(got.approx_eq(&Point2D::new(0.0, 4.0)));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct TransformBuilder<S> {
+    transform: Transform2D<f64, S, S>,
+}
+
+impl<S> TransformBuilder<S> {
+    /// Start with a transform which doesn't do anything.
+    pub fn new() -> Self {
+        TransformBuilder {
+            transform: Transform2D::identity(),
+        }
+    }
+
+    /// Move everything by `displacement`.
+    pub fn translate(self, displacement: Vector2D<f64, S>) -> Self {
+        self.then(Transform2D::create_translation(
+            displacement.x,
+            displacement.y,
+        ))
+    }
+
+    /// Rotate anti-clockwise about the origin.
+    pub fn rotate(self, angle: Angle) -> Self {
+        // Note: euclid's rotations go clockwise
+        self.then(Transform2D::create_rotation(-angle))
+    }
+
+    /// Rotate anti-clockwise about a point.
+    pub fn rotate_about(self, centre: Point2D<f64, S>, angle: Angle) -> Self {
+        self.translate(-centre.to_vector())
+            .rotate(angle)
+            .translate(centre.to_vector())
+    }
+
+    /// Scale relative to the origin.
+    pub fn scale(self, x: f64, y: f64) -> Self {
+        self.then(Transform2D::create_scale(x, y))
+    }
+
+    /// Scale relative to a point.
+    pub fn scale_about(self, centre: Point2D<f64, S>, x: f64, y: f64) -> Self {
+        self.translate(-centre.to_vector())
+            .scale(x, y)
+            .translate(centre.to_vector())
+    }
+
+    /// Reflect everything across the infinite line passing through `line`'s
+    /// end points.
+    ///
+    /// A zero-length [`Line`] has no direction, so it is treated as a
+    /// horizontal line through [`Line::start`].
+    pub fn mirror(self, line: Line<S>) -> Self {
+        let angle = line.angle();
+
+        self.translate(-line.start.to_vector())
+            .rotate(-angle)
+            .scale(1.0, -1.0)
+            .rotate(angle)
+            .translate(line.start.to_vector())
+    }
+
+    /// Apply an arbitrary [`Transform2D`] after everything so far.
+    pub fn then(self, transform: Transform2D<f64, S, S>) -> Self {
+        TransformBuilder {
+            transform: self.transform.post_transform(&transform),
+        }
+    }
+
+    /// Get the combined [`Transform2D`].
+    pub fn build(self) -> Transform2D<f64, S, S> { self.transform }
+}
+
+impl<S> Default for TransformBuilder<S> {
+    fn default() -> Self { TransformBuilder::new() }
+}
+
+impl<S> Copy for TransformBuilder<S> {}
+
+impl<S> Clone for TransformBuilder<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<S> From<TransformBuilder<S>> for Transform2D<f64, S, S> {
+    fn from(builder: TransformBuilder<S>) -> Self { builder.build() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    type Point = euclid::default::Point2D<f64>;
+    type Vector = euclid::default::Vector2D<f64>;
+
+    #[test]
+    fn steps_are_applied_in_order() {
+        let point = Point::new(1.0, 0.0);
+
+        let translate_then_scale = TransformBuilder::new()
+            .translate(Vector::new(1.0, 0.0))
+            .scale(3.0, 3.0)
+            .build();
+        let scale_then_translate = TransformBuilder::new()
+            .scale(3.0, 3.0)
+            .translate(Vector::new(1.0, 0.0))
+            .build();
+
+        assert_eq!(
+            translate_then_scale.transform_point(point),
+            Point::new(6.0, 0.0)
+        );
+        assert_eq!(
+            scale_then_translate.transform_point(point),
+            Point::new(4.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn rotations_are_anticlockwise() {
+        let transform = TransformBuilder::new()
+            .rotate_about(Point::new(1.0, 1.0), Angle::degrees(90.0))
+            .build();
+
+        let got = transform.transform_point(Point::new(2.0, 1.0));
+
+        assert!(got.approx_eq(&Point::new(1.0, 2.0)));
+    }
+
+    #[test]
+    fn scale_about_a_point() {
+        let transform = TransformBuilder::new()
+            .scale_about(Point::new(1.0, 1.0), 2.0, 3.0)
+            .build();
+
+        assert!(transform
+            .transform_point(Point::new(1.0, 1.0))
+            .approx_eq(&Point::new(1.0, 1.0)));
+        assert!(transform
+            .transform_point(Point::new(2.0, 2.0))
+            .approx_eq(&Point::new(3.0, 4.0)));
+    }
+
+    #[test]
+    fn mirror_across_a_diagonal_line() {
+        let line = Line::new(Point::new(0.0, 1.0), Point::new(1.0, 2.0));
+        let transform = TransformBuilder::new().mirror(line).build();
+
+        // points on the line stay put
+        assert!(transform
+            .transform_point(Point::new(5.0, 6.0))
+            .approx_eq(&Point::new(5.0, 6.0)));
+        // everything else swaps sides
+        assert!(transform
+            .transform_point(Point::new(1.0, 0.0))
+            .approx_eq(&Point::new(-1.0, 2.0)));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{algorithms::AffineTransformable, primitives::Arc, BoundingBox};
+use crate::algorithms::AffineTransformable;
 use euclid::{Transform2D, Vector2D};
 
 /// Something which can be moved around "rigidly" in *Drawing Space*.
@@ -27,29 +27,10 @@ impl<Space, A: AffineTransformable> Translate<Space> for A {
     }
 }
 
-impl<Space> Translate<Space> for Arc<Space> {
-    fn translate(&mut self, displacement: Vector2D<f64, Space>) {
-        *self = Arc::from_centre_radius(
-            self.centre().translated(displacement),
-            self.radius(),
-            self.start_angle(),
-            self.sweep_angle(),
-        );
-    }
-}
-
-impl<Space> Translate<Space> for BoundingBox<Space> {
-    fn translate(&mut self, displacement: Vector2D<f64, Space>) {
-        *self = BoundingBox::new_unchecked(
-            self.bottom_left().translated(displacement),
-            self.top_right().translated(displacement),
-        );
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::BoundingBox;
 
     type Point = euclid::default::Point2D<f64>;
     type Vector = euclid::default::Vector2D<f64>;
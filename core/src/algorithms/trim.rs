@@ -0,0 +1,372 @@
+use crate::{
+    algorithms::{
+        arc_arc_intersections, line_arc_intersections, line_line_intersection,
+        ClosestPoint,
+    },
+    normalize_angle,
+    primitives::{Arc, Line},
+    Angle,
+};
+use arrayvec::ArrayVec;
+use euclid::Point2D;
+
+/// How close (as a fraction of the [`Segment`]'s length) an intersection can
+/// be to either end before it's considered to be *at* that end.
+const END_TOLERANCE: f64 = 1e-9;
+
+/// A piece of geometry which can be trimmed, or used as the cutting edge when
+/// trimming something else.
+#[derive(Debug, PartialEq)]
+pub enum Segment<S> {
+    /// A straight [`Line`].
+    Line(Line<S>),
+    /// A circular [`Arc`].
+    Arc(Arc<S>),
+}
+
+impl<S> Segment<S> {
+    /// Find the locations where this [`Segment`] crosses another.
+    pub fn intersections(
+        &self,
+        other: &Segment<S>,
+    ) -> ArrayVec<[Point2D<f64, S>; 2]> {
+        match (self, other) {
+            (Segment::Line(a), Segment::Line(b)) => {
+                line_line_intersection(a, b).into_iter().collect()
+            },
+            (Segment::Line(line), Segment::Arc(arc))
+            | (Segment::Arc(arc), Segment::Line(line)) => {
+                line_arc_intersections(line, arc)
+            },
+            (Segment::Arc(a), Segment::Arc(b)) => arc_arc_intersections(a, b),
+        }
+    }
+
+    /// How far along the [`Segment`] a point is, where `0.0` is the start and
+    /// `1.0` is the end.
+    ///
+    /// The point is assumed to already lie on the [`Segment`].
+    fn parameter_of(&self, point: Point2D<f64, S>) -> f64 {
+        match self {
+            Segment::Line(line) => {
+                let displacement = line.displacement();
+                (point - line.start).dot(displacement)
+                    / displacement.square_length()
+            },
+            Segment::Arc(arc) => {
+                let sweep = arc.sweep_angle();
+                let angle = Line::new(arc.centre(), point).angle();
+                let offset = normalize_angle(
+                    (angle - arc.start_angle()) * sweep.radians.signum(),
+                );
+
+                offset.radians / sweep.radians.abs()
+            },
+        }
+    }
+
+    /// The part of this [`Segment`] between two parameters (see
+    /// [`Segment::parameter_of()`]).
+    fn between(&self, from: f64, to: f64) -> Segment<S> {
+        match self {
+            Segment::Line(line) => {
+                let displacement = line.displacement();
+                Segment::Line(Line::new(
+                    line.start + displacement * from,
+                    line.start + displacement * to,
+                ))
+            },
+            Segment::Arc(arc) => {
+                let sweep = arc.sweep_angle();
+                Segment::Arc(Arc::from_centre_radius(
+                    arc.centre(),
+                    arc.radius(),
+                    arc.start_angle() + sweep * from,
+                    sweep * (to - from),
+                ))
+            },
+        }
+    }
+
+    /// Is this a full circle, with no real start or end?
+    fn is_closed(&self) -> bool {
+        match self {
+            Segment::Line(_) => false,
+            Segment::Arc(arc) => {
+                let sweep = arc.sweep_angle().radians.abs();
+                (sweep - Angle::two_pi().radians).abs() < END_TOLERANCE
+            },
+        }
+    }
+
+    fn distance_to(&self, point: Point2D<f64, S>) -> f64 {
+        let (closest, start) = match self {
+            Segment::Line(line) => (line.closest_point(point), line.start),
+            Segment::Arc(arc) => (arc.closest_point(point), arc.start()),
+        };
+
+        closest
+            .distance_to(point)
+            // every point is the same distance away, so pick one
+            .unwrap_or_else(|| start.distance_to(point))
+    }
+}
+
+impl<S> Copy for Segment<S> {}
+
+impl<S> Clone for Segment<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+/// Split `target` wherever it crosses the `cutting_edge`.
+///
+/// The pieces are returned in order from the start of `target` to its end. If
+/// the two never cross (or only touch at `target`'s ends) you'll get back the
+/// original `target`.
+///
+/// A full circle doesn't really have a start or end, so it is cut into pieces
+/// going from one intersection to the next, starting at the first
+/// intersection after its start angle.
+pub fn split_at_intersections<S>(
+    target: &Segment<S>,
+    cutting_edge: &Segment<S>,
+) -> ArrayVec<[Segment<S>; 3]> {
+    if target.is_closed() {
+        return split_closed(target, cutting_edge);
+    }
+
+    let mut parameters: ArrayVec<[f64; 2]> = target
+        .intersections(cutting_edge)
+        .into_iter()
+        .map(|point| target.parameter_of(point))
+        .filter(|&t| END_TOLERANCE < t && t < 1.0 - END_TOLERANCE)
+        .collect();
+    parameters.sort_by(|a, b| a.total_cmp(b));
+
+    let mut pieces = ArrayVec::new();
+    let mut previous = 0.0;
+
+    for t in parameters {
+        if t - previous > END_TOLERANCE {
+            pieces.push(target.between(previous, t));
+            previous = t;
+        }
+    }
+
+    pieces.push(target.between(previous, 1.0));
+
+    pieces
+}
+
+fn split_closed<S>(
+    target: &Segment<S>,
+    cutting_edge: &Segment<S>,
+) -> ArrayVec<[Segment<S>; 3]> {
+    let mut parameters: ArrayVec<[f64; 2]> = target
+        .intersections(cutting_edge)
+        .into_iter()
+        // the start and end are the same point
+        .map(|point| target.parameter_of(point) % 1.0)
+        .collect();
+    parameters.sort_by(|a, b| a.total_cmp(b));
+
+    let mut pieces = ArrayVec::new();
+
+    match parameters.as_slice() {
+        &[first, second] if second - first > END_TOLERANCE => {
+            pieces.push(target.between(first, second));
+            pieces.push(target.between(second, first + 1.0));
+        },
+        // cutting a circle in one place still leaves one piece
+        _ => pieces.push(*target),
+    }
+
+    pieces
+}
+
+/// Trim `target` back to the `cutting_edge`, removing whichever piece is
+/// closest to the `pick` point (e.g. where the user clicked).
+///
+/// Returns the pieces which are left over, or `None` if the `cutting_edge`
+/// doesn't cross `target` so there is nothing to trim.
+pub fn trim<S>(
+    target: &Segment<S>,
+    cutting_edge: &Segment<S>,
+    pick: Point2D<f64, S>,
+) -> Option<ArrayVec<[Segment<S>; 2]>> {
+    let pieces = split_at_intersections(target, cutting_edge);
+
+    if pieces.len() < 2 {
+        return None;
+    }
+
+    let picked = pieces
+        .iter()
+        .map(|piece| piece.distance_to(pick))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)?;
+
+    Some(
+        pieces
+            .into_iter()
+            .enumerate()
+            .filter(|&(index, _)| index != picked)
+            .map(|(_, piece)| piece)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::{approxeq::ApproxEq, UnknownUnit};
+
+    type Point = euclid::default::Point2D<f64>;
+
+    fn horizontal_line() -> Segment<UnknownUnit> {
+        Segment::Line(Line::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0)))
+    }
+
+    fn as_line(segment: &Segment<UnknownUnit>) -> Line<UnknownUnit> {
+        match segment {
+            Segment::Line(line) => *line,
+            other => panic!("Expected a line but found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn split_a_line_at_a_crossing_line() {
+        let cutting_edge = Segment::Line(Line::new(
+            Point::new(4.0, -1.0),
+            Point::new(4.0, 1.0),
+        ));
+
+        let got = split_at_intersections(&horizontal_line(), &cutting_edge);
+
+        assert_eq!(got.len(), 2);
+        assert!(as_line(&got[0])
+            .approx_eq(&Line::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0))));
+        assert!(as_line(&got[1]).approx_eq(&Line::new(
+            Point::new(4.0, 0.0),
+            Point::new(10.0, 0.0)
+        )));
+    }
+
+    #[test]
+    fn trim_the_picked_end_of_a_line() {
+        let cutting_edge = Segment::Line(Line::new(
+            Point::new(4.0, -1.0),
+            Point::new(4.0, 1.0),
+        ));
+
+        let got = trim(&horizontal_line(), &cutting_edge, Point::new(8.0, 0.5))
+            .unwrap();
+
+        assert_eq!(got.len(), 1);
+        assert!(as_line(&got[0])
+            .approx_eq(&Line::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0))));
+    }
+
+    #[test]
+    fn trim_the_middle_of_a_line_inside_a_circle() {
+        let circle = Segment::Arc(Arc::from_centre_radius(
+            Point::new(5.0, 0.0),
+            2.0,
+            Angle::zero(),
+            Angle::two_pi(),
+        ));
+
+        let got =
+            trim(&horizontal_line(), &circle, Point::new(5.0, 0.1)).unwrap();
+
+        assert_eq!(got.len(), 2);
+        assert!(as_line(&got[0])
+            .approx_eq(&Line::new(Point::new(0.0, 0.0), Point::new(3.0, 0.0))));
+        assert!(as_line(&got[1]).approx_eq(&Line::new(
+            Point::new(7.0, 0.0),
+            Point::new(10.0, 0.0)
+        )));
+    }
+
+    #[test]
+    fn trim_an_arc_with_a_line() {
+        let semicircle = Segment::Arc(Arc::from_centre_radius(
+            Point::zero(),
+            1.0,
+            Angle::zero(),
+            Angle::pi(),
+        ));
+        let vertical = Segment::Line(Line::new(
+            Point::new(0.0, -2.0),
+            Point::new(0.0, 2.0),
+        ));
+
+        let got = trim(&semicircle, &vertical, Point::new(-1.0, 0.1)).unwrap();
+
+        match got.as_slice() {
+            [Segment::Arc(arc)] => {
+                assert!(arc.approx_eq(&Arc::from_centre_radius(
+                    Point::zero(),
+                    1.0,
+                    Angle::zero(),
+                    Angle::frac_pi_2()
+                )))
+            },
+            other => panic!("Expected a single arc but found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn trim_a_full_circle_across_its_start_angle() {
+        let circle = Segment::Arc(Arc::from_centre_radius(
+            Point::zero(),
+            1.0,
+            Angle::zero(),
+            Angle::two_pi(),
+        ));
+        let vertical = Segment::Line(Line::new(
+            Point::new(0.0, -2.0),
+            Point::new(0.0, 2.0),
+        ));
+
+        let got = trim(&circle, &vertical, Point::new(1.0, 0.0)).unwrap();
+
+        // the entire right half is removed, not just the first quarter
+        match got.as_slice() {
+            [Segment::Arc(arc)] => {
+                assert!(arc.approx_eq(&Arc::from_centre_radius(
+                    Point::zero(),
+                    1.0,
+                    Angle::frac_pi_2(),
+                    Angle::pi()
+                )))
+            },
+            other => panic!("Expected a single arc but found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn nothing_to_trim_without_an_intersection() {
+        let cutting_edge = Segment::Line(Line::new(
+            Point::new(4.0, 1.0),
+            Point::new(4.0, 2.0),
+        ));
+
+        let got = trim(&horizontal_line(), &cutting_edge, Point::new(8.0, 0.0));
+
+        assert!(got.is_none());
+    }
+
+    #[test]
+    fn touching_an_end_is_not_an_intersection() {
+        let cutting_edge = Segment::Line(Line::new(
+            Point::new(10.0, -1.0),
+            Point::new(10.0, 1.0),
+        ));
+
+        let got = split_at_intersections(&horizontal_line(), &cutting_edge);
+
+        assert_eq!(got.as_slice(), &[horizontal_line()]);
+    }
+}
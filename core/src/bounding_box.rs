@@ -1,5 +1,7 @@
 use crate::algorithms::Bounded;
-use euclid::{num::Zero, Length, Point2D, Size2D, Vector2D};
+use euclid::{
+    approxeq::ApproxEq, num::Zero, Length, Point2D, Size2D, Vector2D,
+};
 
 /// An axis-aligned bounding box.
 #[derive(Debug, PartialEq)]
@@ -95,7 +97,10 @@ impl<S> BoundingBox<S> {
         left: BoundingBox<S>,
         right: BoundingBox<S>,
     ) -> BoundingBox<S> {
-        BoundingBox::new(left.bottom_left, right.top_right)
+        BoundingBox::new_unchecked(
+            left.bottom_left.min(right.bottom_left),
+            left.top_right.max(right.top_right),
+        )
     }
 
     /// Create a [`BoundingBox`] which fully encompasses a set of [`Bounded`]
@@ -142,6 +147,31 @@ impl<S> BoundingBox<S> {
     /// The maximum Y value.
     pub fn max_y(self) -> f64 { self.top_right.y }
 
+    /// Grow the [`BoundingBox`] by `margin` on every side.
+    ///
+    /// A negative `margin` will shrink the box instead. If it would shrink
+    /// past zero width or height, that dimension collapses to the box's
+    /// centre.
+    pub fn expanded(self, margin: Length<f64, S>) -> BoundingBox<S> {
+        let margin = margin.get();
+
+        let expand = |min: f64, max: f64| {
+            if max - min + 2.0 * margin >= 0.0 {
+                (min - margin, max + margin)
+            } else {
+                let centre = (min + max) / 2.0;
+                (centre, centre)
+            }
+        };
+        let (min_x, max_x) = expand(self.min_x(), self.max_x());
+        let (min_y, max_y) = expand(self.min_y(), self.max_y());
+
+        BoundingBox::new_unchecked(
+            Point2D::new(min_x, min_y),
+            Point2D::new(max_x, max_y),
+        )
+    }
+
     /// Does this [`BoundingBox`] fully contain another?
     pub fn fully_contains(self, other: BoundingBox<S>) -> bool {
         self.min_x() <= other.min_x()
@@ -151,9 +181,38 @@ impl<S> BoundingBox<S> {
     }
 
     /// Do these two [`BoundingBox`]es overlap?
+    ///
+    /// Boxes which only touch along an edge or at a corner count as
+    /// overlapping.
     pub fn intersects_with(&self, other: BoundingBox<S>) -> bool {
-        // FIXME: Actually implement this
-        self.fully_contains(other)
+        self.min_x() <= other.max_x()
+            && other.min_x() <= self.max_x()
+            && self.min_y() <= other.max_y()
+            && other.min_y() <= self.max_y()
+    }
+
+    /// The region where two [`BoundingBox`]es overlap, or `None` if they
+    /// don't intersect.
+    pub fn intersection(self, other: BoundingBox<S>) -> Option<BoundingBox<S>> {
+        if self.intersects_with(other) {
+            Some(BoundingBox::new_unchecked(
+                self.bottom_left.max(other.bottom_left),
+                self.top_right.min(other.top_right),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<S> ApproxEq<f64> for BoundingBox<S> {
+    fn approx_epsilon() -> f64 { f64::approx_epsilon() }
+
+    fn approx_eq_eps(&self, other: &Self, approx_epsilon: &f64) -> bool {
+        let eps = Point2D::new(*approx_epsilon, *approx_epsilon);
+
+        self.bottom_left.approx_eq_eps(&other.bottom_left, &eps)
+            && self.top_right.approx_eq_eps(&other.top_right, &eps)
     }
 }
 
@@ -195,4 +254,93 @@ mod tests {
 
         assert_eq!(got, original);
     }
+
+    #[test]
+    fn merging_contains_both_boxes() {
+        let left = BoundingBox::new(Point2D::zero(), Point2D::new(10.0, 10.0));
+        let right =
+            BoundingBox::new(Point2D::new(2.0, -5.0), Point2D::new(3.0, 3.0));
+
+        let got = BoundingBox::merge(left, right);
+
+        assert_eq!(
+            got,
+            BoundingBox::new(Point2D::new(0.0, -5.0), Point2D::new(10.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn approximately_equal_bounding_boxes() {
+        let bounds = BoundingBox::new(Point2D::zero(), Point2D::new(1.0, 1.0));
+        let nudged =
+            BoundingBox::new(Point2D::zero(), Point2D::new(1.0, 1.0 + 1e-9));
+        let bigger = BoundingBox::new(Point2D::zero(), Point2D::new(2.0, 1.0));
+
+        assert!(bounds.approx_eq(&nudged));
+        assert!(!bounds.approx_eq(&bigger));
+    }
+
+    #[test]
+    fn expand_then_inset_gives_the_original() {
+        let original =
+            BoundingBox::new(Point2D::new(1.0, 2.0), Point2D::new(4.0, 3.0));
+        let margin = Length::new(0.5);
+
+        let expanded = original.expanded(margin);
+
+        assert_eq!(expanded.bottom_left(), Point2D::new(0.5, 1.5));
+        assert_eq!(expanded.top_right(), Point2D::new(4.5, 3.5));
+        assert_eq!(expanded.expanded(-margin), original);
+    }
+
+    #[test]
+    fn insetting_too_far_collapses_to_the_centre() {
+        let original =
+            BoundingBox::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 2.0));
+
+        let got = original.expanded(Length::new(-2.0));
+
+        assert_eq!(got.bottom_left(), Point2D::new(2.0, 1.0));
+        assert_eq!(got.top_right(), Point2D::new(8.0, 1.0));
+    }
+
+    #[test]
+    fn partially_overlapping_boxes_intersect() {
+        let first =
+            BoundingBox::new(Point2D::new(0.0, 0.0), Point2D::new(4.0, 4.0));
+        let second =
+            BoundingBox::new(Point2D::new(2.0, 3.0), Point2D::new(6.0, 5.0));
+
+        assert!(first.intersects_with(second));
+        assert!(second.intersects_with(first));
+        assert_eq!(
+            first.intersection(second),
+            Some(BoundingBox::new(
+                Point2D::new(2.0, 3.0),
+                Point2D::new(4.0, 4.0)
+            ))
+        );
+    }
+
+    #[test]
+    fn intersection_with_a_box_inside_is_the_inner_box() {
+        let outer =
+            BoundingBox::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0));
+        let inner =
+            BoundingBox::new(Point2D::new(2.0, 3.0), Point2D::new(4.0, 5.0));
+
+        assert_eq!(outer.intersection(inner), Some(inner));
+        assert_eq!(inner.intersection(outer), Some(inner));
+    }
+
+    #[test]
+    fn separate_boxes_have_no_intersection() {
+        let first =
+            BoundingBox::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0));
+        let second =
+            BoundingBox::new(Point2D::new(2.0, 0.0), Point2D::new(3.0, 1.0));
+
+        assert!(!first.intersects_with(second));
+        assert_eq!(first.intersection(second), None);
+    }
 }
@@ -0,0 +1,230 @@
+//! Conversions between our primitives and their [`kurbo`] equivalents.
+//!
+//! Coordinates are copied across as-is. [`kurbo`] doesn't know about
+//! coordinate spaces, so it's up to the caller to make sure points are in the
+//! right one (e.g. by transforming to canvas space before converting).
+
+use crate::{
+    primitives::{Arc, ArcError, Line},
+    Angle,
+};
+use euclid::Point2D;
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+fn to_kurbo<S>(point: Point2D<f64, S>) -> kurbo::Point {
+    kurbo::Point::new(point.x, point.y)
+}
+
+fn from_kurbo<S>(point: kurbo::Point) -> Point2D<f64, S> {
+    Point2D::new(point.x, point.y)
+}
+
+impl<S> From<kurbo::Line> for Line<S> {
+    fn from(line: kurbo::Line) -> Line<S> {
+        Line::new(from_kurbo(line.p0), from_kurbo(line.p1))
+    }
+}
+
+impl<S> From<Line<S>> for kurbo::Line {
+    fn from(line: Line<S>) -> kurbo::Line {
+        kurbo::Line::new(to_kurbo(line.start), to_kurbo(line.end))
+    }
+}
+
+impl<S> TryFrom<kurbo::Arc> for Arc<S> {
+    type Error = ArcConversionError;
+
+    /// Convert a [`kurbo::Arc`], failing if it is part of an ellipse rather
+    /// than a circle or it isn't a valid [`Arc`] (e.g. the radius is zero).
+    fn try_from(arc: kurbo::Arc) -> Result<Arc<S>, ArcConversionError> {
+        let kurbo::Vec2 { x: rx, y: ry } = arc.radii;
+
+        if (rx - ry).abs() > f64::EPSILON * rx.abs().max(ry.abs()) {
+            return Err(ArcConversionError::NotCircular { radii: arc.radii });
+        }
+
+        // rotating a circle is the same as changing where it starts
+        let arc = Arc::try_new(
+            from_kurbo(arc.center),
+            rx,
+            Angle::radians(arc.start_angle + arc.x_rotation),
+            Angle::radians(arc.sweep_angle),
+        )?;
+
+        Ok(arc)
+    }
+}
+
+impl<S> From<Arc<S>> for kurbo::Arc {
+    fn from(arc: Arc<S>) -> kurbo::Arc {
+        kurbo::Arc {
+            center: to_kurbo(arc.centre()),
+            radii: kurbo::Vec2::new(arc.radius(), arc.radius()),
+            start_angle: arc.start_angle().radians,
+            sweep_angle: arc.sweep_angle().radians,
+            x_rotation: 0.0,
+        }
+    }
+}
+
+/// The error returned when a [`kurbo::Arc`] can't be converted into an
+/// [`Arc`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ArcConversionError {
+    /// The [`kurbo::Arc`] is part of an ellipse rather than a circle.
+    NotCircular {
+        /// The arc's radii in the x and y directions.
+        radii: kurbo::Vec2,
+    },
+    /// The arc is circular, but isn't a valid [`Arc`].
+    InvalidArc(ArcError),
+}
+
+impl From<ArcError> for ArcConversionError {
+    fn from(e: ArcError) -> ArcConversionError {
+        ArcConversionError::InvalidArc(e)
+    }
+}
+
+impl Display for ArcConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ArcConversionError::NotCircular { radii } => write!(
+                f,
+                "The arc isn't circular (radii are {} and {})",
+                radii.x, radii.y
+            ),
+            ArcConversionError::InvalidArc(_) => {
+                write!(f, "The arc isn't valid")
+            },
+        }
+    }
+}
+
+impl Error for ArcConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArcConversionError::NotCircular { .. } => None,
+            ArcConversionError::InvalidArc(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    #[test]
+    fn round_trip_a_line() {
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(-3.0, 4.0));
+
+        let kurbo_line = kurbo::Line::from(line);
+        let got: Line<euclid::UnknownUnit> = Line::from(kurbo_line);
+
+        assert_eq!(kurbo_line.p0, kurbo::Point::new(1.0, 2.0));
+        assert_eq!(kurbo_line.p1, kurbo::Point::new(-3.0, 4.0));
+        assert_eq!(got, line);
+    }
+
+    #[test]
+    fn round_trip_an_arc() {
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 2.0),
+            5.0,
+            Angle::frac_pi_4(),
+            -Angle::frac_pi_2(),
+        );
+
+        let got: Arc<euclid::UnknownUnit> =
+            Arc::try_from(kurbo::Arc::from(arc)).unwrap();
+
+        assert_eq!(got, arc);
+    }
+
+    #[test]
+    fn rotated_circular_arcs_start_later() {
+        let arc = kurbo::Arc {
+            center: kurbo::Point::ORIGIN,
+            radii: kurbo::Vec2::new(2.0, 2.0),
+            start_angle: 0.0,
+            sweep_angle: std::f64::consts::FRAC_PI_2,
+            x_rotation: std::f64::consts::FRAC_PI_2,
+        };
+
+        let got: Arc<euclid::UnknownUnit> = Arc::try_from(arc).unwrap();
+
+        assert!(got.start().approx_eq(&Point::new(0.0, 2.0)));
+        assert!(got.end().approx_eq(&Point::new(-2.0, 0.0)));
+    }
+
+    #[test]
+    fn elliptical_arcs_are_rejected() {
+        let arc = kurbo::Arc {
+            center: kurbo::Point::ORIGIN,
+            radii: kurbo::Vec2::new(2.0, 1.0),
+            start_angle: 0.0,
+            sweep_angle: 1.0,
+            x_rotation: 0.0,
+        };
+
+        let got = Arc::<euclid::UnknownUnit>::try_from(arc);
+
+        assert_eq!(
+            got,
+            Err(ArcConversionError::NotCircular {
+                radii: kurbo::Vec2::new(2.0, 1.0)
+            })
+        );
+    }
+
+    #[test]
+    fn a_zero_radius_is_an_error() {
+        let arc = kurbo::Arc {
+            center: kurbo::Point::ORIGIN,
+            radii: kurbo::Vec2::new(0.0, 0.0),
+            start_angle: 0.0,
+            sweep_angle: 1.0,
+            x_rotation: 0.0,
+        };
+
+        let got = Arc::<euclid::UnknownUnit>::try_from(arc);
+
+        assert_eq!(
+            got,
+            Err(ArcConversionError::InvalidArc(ArcError::InvalidRadius(0.0)))
+        );
+    }
+
+    #[test]
+    fn negative_and_nan_radii_are_errors() {
+        for &radius in &[-1.0, f64::NAN] {
+            let arc = kurbo::Arc {
+                center: kurbo::Point::ORIGIN,
+                radii: kurbo::Vec2::new(radius, radius),
+                start_angle: 0.0,
+                sweep_angle: 1.0,
+                x_rotation: 0.0,
+            };
+
+            let got = Arc::<euclid::UnknownUnit>::try_from(arc);
+
+            assert!(
+                matches!(
+                    got,
+                    Err(ArcConversionError::InvalidArc(
+                        ArcError::InvalidRadius(_)
+                    ))
+                ),
+                "{:?}",
+                got
+            );
+        }
+    }
+}
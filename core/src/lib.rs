@@ -7,17 +7,21 @@
     missing_debug_implementations,
     missing_copy_implementations,
     missing_docs,
-    intra_doc_link_resolution_failure
+    rustdoc::broken_intra_doc_links
 )]
 
 pub mod algorithms;
 mod bounding_box;
+#[cfg(feature = "kurbo")]
+mod kurbo_interop;
 mod orientation;
 pub mod primitives;
+mod types;
 
 pub use bounding_box::BoundingBox;
+#[cfg(feature = "kurbo")]
+pub use kurbo_interop::ArcConversionError;
 pub use orientation::{centre_of_three_points, Orientation};
-
-/// A strongly-typed angle, useful for dealing with the pesky modular arithmetic
-/// normally associated with circles and angles.
-pub type Angle = euclid::Angle<f64>;
+pub use types::{
+    angle_between, angle_in_range, bisect, normalize_angle, Angle,
+};
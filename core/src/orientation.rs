@@ -1,6 +1,14 @@
 use euclid::{Point2D, Vector2D};
 
 /// How something may be oriented.
+///
+/// This follows the usual mathematical convention, where the y-axis points
+/// *up*. Turning left (e.g. `(0, 0) → (1, 0) → (1, 1)`) is
+/// [`Orientation::Anticlockwise`] and matches a positive sweep angle on an
+/// [`crate::primitives::Arc`].
+///
+/// If you are working in a coordinate space where the y-axis points down
+/// (e.g. most canvases) the two directions will appear swapped on screen.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Orientation {
     /// The points are arranged in a clockwise direction.
@@ -13,18 +21,32 @@ pub enum Orientation {
 
 impl Orientation {
     /// Find the orientation of 3 [`Point2D`]s.
+    ///
+    /// This is the sign of the cross product, `(second - first) × (third -
+    /// second)`, where positive means [`Orientation::Anticlockwise`].
+    ///
+    /// ```rust
+    /// # use arcs_core::Orientation;
+    /// # type Point = euclid::default::Point2D<f64>;
+    /// let first = Point::new(0.0, 0.0);
+    /// let second = Point::new(1.0, 0.0);
+    /// let third = Point::new(1.0, 1.0);
+    ///
+    /// let got = Orientation::of(first, second, third);
+    ///
+    /// assert_eq!(got, Orientation::Anticlockwise);
+    /// ```
     pub fn of<S>(
         first: Point2D<f64, S>,
         second: Point2D<f64, S>,
         third: Point2D<f64, S>,
     ) -> Orientation {
-        let value = (second.y - first.y) * (third.x - second.x)
-            - (second.x - first.x) * (third.y - second.y);
+        let value = (second - first).cross(third - second);
 
         if value > 0.0 {
-            Orientation::Clockwise
-        } else if value < 0.0 {
             Orientation::Anticlockwise
+        } else if value < 0.0 {
+            Orientation::Clockwise
         } else {
             Orientation::Collinear
         }
@@ -93,4 +115,23 @@ mod tests {
 
         assert_eq!(centre, Point2D::zero());
     }
+
+    #[test]
+    fn turning_left_is_anticlockwise() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(1.0, 0.0);
+        let c = Point2D::new(1.0, 1.0);
+
+        assert_eq!(Orientation::of(a, b, c), Orientation::Anticlockwise);
+        assert_eq!(Orientation::of(c, b, a), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn points_in_a_line_are_collinear() {
+        let a = Point2D::new(0.0, 0.0);
+        let b = Point2D::new(1.0, 1.0);
+        let c = Point2D::new(5.0, 5.0);
+
+        assert_eq!(Orientation::of(a, b, c), Orientation::Collinear);
+    }
 }
@@ -1,8 +1,12 @@
 #![allow(missing_docs)]
 
-use crate::{Angle, Orientation};
-use euclid::{Point2D, Vector2D};
-use std::f64::consts::PI;
+use crate::{angle_in_range, normalize_angle, Angle, Orientation};
+use euclid::{approxeq::ApproxEq, Point2D, Vector2D};
+use std::{
+    error::Error,
+    f64::consts::PI,
+    fmt::{self, Display, Formatter},
+};
 
 /// A circle segment.
 #[derive(Debug, PartialEq)]
@@ -16,6 +20,14 @@ pub struct Arc<S> {
 impl<S> Arc<S> {
     /// Create an [`Arc`] based upon its centre and radius.
     ///
+    /// A `sweep_angle` of `±2π` is a full circle, and only the radius is
+    /// checked in debug builds, so full circles are always accepted.
+    ///
+    /// The radius check is skipped in release builds, so a zero, negative, or
+    /// `NaN` radius will silently give you a broken [`Arc`]. Use
+    /// [`Arc::try_new()`] when the values come from somewhere you don't
+    /// control (e.g. a file being imported).
+    ///
     /// # Examples
     pub fn from_centre_radius(
         centre: Point2D<f64, S>,
@@ -33,6 +45,41 @@ impl<S> Arc<S> {
         }
     }
 
+    /// A checked version of [`Arc::from_centre_radius()`] which makes sure
+    /// the radius is positive and everything is finite.
+    ///
+    /// ```rust
+    /// # use arcs_core::{primitives::{Arc, ArcError}, Angle};
+    /// # type Point = euclid::default::Point2D<f64>;
+    /// let arc = Arc::try_new(Point::zero(), 0.0, Angle::zero(), Angle::pi());
+    ///
+    /// assert_eq!(arc, Err(ArcError::InvalidRadius(0.0)));
+    /// ```
+    pub fn try_new(
+        centre: Point2D<f64, S>,
+        radius: f64,
+        start_angle: Angle,
+        sweep_angle: Angle,
+    ) -> Result<Self, ArcError> {
+        if !(centre.x.is_finite() && centre.y.is_finite()) {
+            return Err(ArcError::NonFiniteCentre);
+        }
+        if !(radius.is_finite() && radius > 0.0) {
+            return Err(ArcError::InvalidRadius(radius));
+        }
+        if !(start_angle.radians.is_finite() && sweep_angle.radians.is_finite())
+        {
+            return Err(ArcError::NonFiniteAngle);
+        }
+
+        Ok(Arc::from_centre_radius(
+            centre,
+            radius,
+            start_angle,
+            sweep_angle,
+        ))
+    }
+
     /// Try to find the [`Arc`] which will pass through three points.
     ///
     /// # Examples
@@ -74,7 +121,7 @@ impl<S> Arc<S> {
         end: Point2D<f64, S>,
     ) -> Option<Self> {
         let centre = crate::centre_of_three_points(start, middle, end)?;
-        let radius = (start - centre).length();
+        let radius = start.distance_to(centre);
         let start_angle = (start - centre).angle_from_x_axis();
         let sweep_angle = sweep_angle_from_3_points(start, middle, end, centre);
 
@@ -106,6 +153,20 @@ impl<S> Arc<S> {
 
     pub fn end(self) -> Point2D<f64, S> { self.point_at(self.sweep_angle()) }
 
+    /// The point half-way along the [`Arc`].
+    pub fn mid_point(self) -> Point2D<f64, S> {
+        self.point_at(self.sweep_angle() / 2.0)
+    }
+
+    /// How far the middle of the [`Arc`] bulges away from the chord joining
+    /// its start and end points.
+    ///
+    /// For a major arc this is more than the radius.
+    pub fn sagitta(self) -> f64 {
+        let half_sweep = self.sweep_angle().radians.abs() / 2.0;
+        self.radius() * (1.0 - half_sweep.cos())
+    }
+
     pub fn point_at(self, angle: Angle) -> Point2D<f64, S> {
         let angle = self.start_angle() + angle;
         let (sin, cos) = angle.sin_cos();
@@ -114,17 +175,60 @@ impl<S> Arc<S> {
         self.centre() + Vector2D::new(r * cos, r * sin)
     }
 
+    /// Does the [`Arc`] sweep through a particular angle?
+    ///
+    /// Angles are compared modulo a full revolution, so `-90°` and `270°` are
+    /// treated the same.
     pub fn contains_angle(self, angle: Angle) -> bool {
-        let start_angle = self.start_angle();
-        let end_angle = self.end_angle();
+        angle_in_range(angle, self.start_angle(), self.sweep_angle())
+    }
 
-        let (min, max) = if start_angle < end_angle {
-            (start_angle, end_angle)
+    /// Is the point inside the pie slice swept out by this [`Arc`]?
+    ///
+    /// This treats the [`Arc`] as a filled sector, so the point must be no
+    /// further than [`Arc::radius()`] from the centre and lie within the
+    /// sweep. Points exactly on the edge count as inside, and the centre is
+    /// always inside. Use [`Arc::on_boundary()`] to check against the curve
+    /// itself.
+    pub fn contains_point(self, point: Point2D<f64, S>) -> bool {
+        let offset = point - self.centre();
+
+        if offset.length() > self.radius() {
+            false
+        } else if offset == Vector2D::zero() {
+            true
         } else {
-            (end_angle, start_angle)
-        };
+            self.contains_angle(Angle::radians(offset.y.atan2(offset.x)))
+        }
+    }
+
+    /// Is the point within `tolerance` of the curve?
+    ///
+    /// Unlike [`Arc::contains_point()`] this only considers the curved edge,
+    /// not the straight edges joining its ends to the centre.
+    pub fn on_boundary(self, point: Point2D<f64, S>, tolerance: f64) -> bool {
+        let offset = point - self.centre();
+
+        if offset != Vector2D::zero()
+            && self.contains_angle(Angle::radians(offset.y.atan2(offset.x)))
+        {
+            (offset.length() - self.radius()).abs() <= tolerance
+        } else {
+            // the closest point on the curve must be one of the ends
+            self.start().distance_to(point) <= tolerance
+                || self.end().distance_to(point) <= tolerance
+        }
+    }
 
-        (min <= angle) && (angle <= max)
+    /// Get an [`Arc`] covering the same points, but sweeping in the opposite
+    /// direction.
+    pub fn reverse(self) -> Self {
+        Arc::from_centre_radius(
+            self.centre(),
+            self.radius(),
+            self.end_angle(),
+            -self.sweep_angle(),
+        )
     }
 
     pub fn is_minor_arc(&self) -> bool {
@@ -140,25 +244,36 @@ fn sweep_angle_from_3_points<S>(
     end: Point2D<f64, S>,
     centre: Point2D<f64, S>,
 ) -> Angle {
-    debug_assert!(
-        Orientation::of(start, middle, end) != Orientation::Collinear
-    );
-
-    let start_ray = start - centre;
-    let end_ray = end - centre;
-    let orientation = Orientation::of(start, middle, end);
-    let angular_difference =
-        end_ray.angle_from_x_axis() - start_ray.angle_from_x_axis();
-
-    if angular_difference.radians > 0.0 && orientation == Orientation::Clockwise
-    {
-        angular_difference - Angle::two_pi()
-    } else if angular_difference.radians < 0.0
-        && orientation == Orientation::Anticlockwise
-    {
-        Angle::two_pi() - angular_difference
-    } else {
-        angular_difference
+    let start_angle = (start - centre).angle_from_x_axis();
+    let end_angle = (end - centre).angle_from_x_axis();
+
+    match Orientation::of(start, middle, end) {
+        Orientation::Anticlockwise => normalize_angle(end_angle - start_angle),
+        Orientation::Clockwise => -normalize_angle(start_angle - end_angle),
+        Orientation::Collinear => unreachable!(),
+    }
+}
+
+/// Compares each [`Arc`]'s centre, radius, and angles.
+///
+/// Angles are compared directly, so two [`Arc`]s covering the same points but
+/// with start angles a full revolution apart won't be equal.
+impl<S> ApproxEq<f64> for Arc<S> {
+    fn approx_epsilon() -> f64 { f64::approx_epsilon() }
+
+    fn approx_eq_eps(&self, other: &Self, approx_epsilon: &f64) -> bool {
+        let eps = Point2D::new(*approx_epsilon, *approx_epsilon);
+
+        self.centre.approx_eq_eps(&other.centre, &eps)
+            && self.radius.approx_eq_eps(&other.radius, approx_epsilon)
+            && self
+                .start_angle
+                .radians
+                .approx_eq_eps(&other.start_angle.radians, approx_epsilon)
+            && self
+                .sweep_angle
+                .radians
+                .approx_eq_eps(&other.sweep_angle.radians, approx_epsilon)
     }
 }
 
@@ -168,10 +283,39 @@ impl<S> Clone for Arc<S> {
     fn clone(&self) -> Self { *self }
 }
 
+/// The reason [`Arc::try_new()`] couldn't create an [`Arc`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ArcError {
+    /// The radius must be finite and greater than zero.
+    InvalidRadius(f64),
+    /// The centre contains an infinite or `NaN` coordinate.
+    NonFiniteCentre,
+    /// The start or sweep angle is infinite or `NaN`.
+    NonFiniteAngle,
+}
+
+impl Display for ArcError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ArcError::InvalidRadius(radius) => {
+                write!(f, "{} isn't a valid radius", radius)
+            },
+            ArcError::NonFiniteCentre => {
+                write!(f, "The centre must be a finite point")
+            },
+            ArcError::NonFiniteAngle => {
+                write!(f, "The start and sweep angles must be finite")
+            },
+        }
+    }
+}
+
+impl Error for ArcError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use euclid::{approxeq::ApproxEq, UnknownUnit};
+    use euclid::UnknownUnit;
 
     type Point = euclid::default::Point2D<f64>;
     type Vector = euclid::default::Vector2D<f64>;
@@ -205,6 +349,24 @@ mod tests {
     test_contains_angle!(inside_reverse_arc,
         Arc::from_centre_radius(Point::zero(), 1.0, Angle::frac_pi_4(), -Angle::frac_pi_4()),
         45.0 => true);
+    test_contains_angle!(arc_crossing_the_positive_x_axis,
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::degrees(315.0), Angle::frac_pi_2()),
+        0.0 => true);
+    test_contains_angle!(negative_angle_equivalent,
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::pi(), Angle::pi()),
+        -90.0 => true);
+    test_contains_angle!(arc_crossing_the_negative_x_axis,
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::degrees(170.0), Angle::degrees(20.0)),
+        -175.0 => true);
+    test_contains_angle!(clockwise_arc_crossing_the_negative_x_axis,
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::degrees(-170.0), Angle::degrees(-20.0)),
+        180.0 => true);
+    test_contains_angle!(outside_arc_crossing_the_negative_x_axis,
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::degrees(170.0), Angle::degrees(20.0)),
+        0.0 => false);
+    test_contains_angle!(clockwise_arc_crossing_the_positive_x_axis,
+        Arc::from_centre_radius(Point::zero(), 1.0, Angle::frac_pi_4(), -Angle::frac_pi_2()),
+        -30.0 => true);
 
     #[test]
     fn arc_from_three_points() {
@@ -240,6 +402,32 @@ mod tests {
         assert_eq!(got, expected);
     }
 
+    #[test]
+    fn anticlockwise_arc_crossing_the_seam() {
+        let start = Point::new(-10.0, 0.0);
+        let middle = Point::new(0.0, -10.0);
+        let end = Point::new(10.0, 0.0);
+
+        let got = Arc::from_three_points(start, middle, end).unwrap();
+
+        assert!(got.is_anticlockwise());
+        assert!(got.sweep_angle().radians.approx_eq(&PI));
+        assert!(got.contains_angle(Angle::degrees(270.0)));
+        assert!(!got.contains_angle(Angle::degrees(90.0)));
+    }
+
+    #[test]
+    fn major_arc_from_three_points() {
+        let start = Point::new(10.0, 0.0);
+        let middle = Point::new(-10.0, 0.0);
+        let end = Point::new(0.0, -10.0);
+
+        let got = Arc::from_three_points(start, middle, end).unwrap();
+
+        assert!(got.is_anticlockwise());
+        assert!(got.sweep_angle().radians.approx_eq(&(1.5 * PI)));
+    }
+
     #[test]
     fn basic_properties() {
         let centre = Point::new(5.0, 100.0);
@@ -260,4 +448,183 @@ mod tests {
         let expected_end = centre + Vector::new(0.0, radius);
         assert!(arc.end().approx_eq(&expected_end));
     }
+
+    #[test]
+    fn reversing_swaps_the_start_and_end() {
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 2.0),
+            3.0,
+            Angle::frac_pi_4(),
+            Angle::frac_pi_2() * 3.0,
+        );
+
+        let got = arc.reverse();
+
+        assert!(got.start().approx_eq(&arc.end()));
+        assert!(got.end().approx_eq(&arc.start()));
+        assert!(got.is_clockwise());
+        for degrees in (0..360).step_by(15) {
+            let angle = Angle::degrees(degrees as f64);
+            assert_eq!(got.contains_angle(angle), arc.contains_angle(angle));
+        }
+    }
+
+    #[test]
+    fn approximately_equal_arcs() {
+        let arc: Arc<UnknownUnit> = Arc::from_centre_radius(
+            Point::new(1.0, 2.0),
+            3.0,
+            Angle::frac_pi_4(),
+            Angle::frac_pi_2(),
+        );
+        let nudged = Arc::from_centre_radius(
+            arc.centre(),
+            arc.radius() + 1e-9,
+            arc.start_angle(),
+            arc.sweep_angle(),
+        );
+        let reversed = Arc::from_centre_radius(
+            arc.centre(),
+            arc.radius(),
+            arc.start_angle(),
+            -arc.sweep_angle(),
+        );
+
+        assert!(arc.approx_eq(&nudged));
+        assert!(!arc.approx_eq(&reversed));
+    }
+
+    #[test]
+    fn checked_constructor() {
+        let centre = Point::new(1.0, 2.0);
+        let start = Angle::zero();
+        let sweep = Angle::frac_pi_2();
+
+        let got: Arc<UnknownUnit> =
+            Arc::try_new(centre, 3.0, start, sweep).unwrap();
+        assert_eq!(got, Arc::from_centre_radius(centre, 3.0, start, sweep));
+
+        let inputs = vec![
+            (centre, -1.0, start, sweep, ArcError::InvalidRadius(-1.0)),
+            (
+                centre,
+                f64::INFINITY,
+                start,
+                sweep,
+                ArcError::InvalidRadius(f64::INFINITY),
+            ),
+            (
+                Point::new(f64::NAN, 0.0),
+                1.0,
+                start,
+                sweep,
+                ArcError::NonFiniteCentre,
+            ),
+            (
+                centre,
+                1.0,
+                Angle::radians(f64::NAN),
+                sweep,
+                ArcError::NonFiniteAngle,
+            ),
+            (
+                centre,
+                1.0,
+                start,
+                Angle::radians(f64::INFINITY),
+                ArcError::NonFiniteAngle,
+            ),
+        ];
+
+        for (centre, radius, start, sweep, expected) in inputs {
+            let got: Result<Arc<UnknownUnit>, _> =
+                Arc::try_new(centre, radius, start, sweep);
+            assert_eq!(got, Err(expected));
+        }
+
+        let got: Result<Arc<UnknownUnit>, _> =
+            Arc::try_new(centre, f64::NAN, start, sweep);
+        assert!(matches!(got, Err(ArcError::InvalidRadius(r)) if r.is_nan()));
+    }
+
+    #[test]
+    fn points_inside_a_pie_slice() {
+        let arc: Arc<UnknownUnit> = Arc::from_centre_radius(
+            Point::new(1.0, 1.0),
+            2.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+
+        assert!(arc.contains_point(Point::new(2.0, 2.0)));
+        assert!(arc.contains_point(arc.centre()));
+        assert!(arc.contains_point(arc.mid_point()));
+        // outside the radius
+        assert!(!arc.contains_point(Point::new(2.5, 2.5)));
+        // inside the circle, but not the sweep
+        assert!(!arc.contains_point(Point::new(0.0, 0.0)));
+        assert!(!arc.reverse().contains_point(Point::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn points_on_the_curve() {
+        let arc: Arc<UnknownUnit> = Arc::from_centre_radius(
+            Point::zero(),
+            2.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+
+        assert!(arc.on_boundary(arc.mid_point(), 1e-6));
+        assert!(arc.on_boundary(Point::new(0.0, 2.05), 0.1));
+        assert!(arc.on_boundary(Point::new(2.05, -0.05), 0.1));
+        // inside the pie slice isn't on the curve
+        assert!(!arc.on_boundary(Point::new(1.0, 1.0), 0.1));
+        assert!(!arc.on_boundary(arc.centre(), 0.1));
+        // on the circle, but outside the sweep
+        assert!(!arc.on_boundary(Point::new(-2.0, 0.0), 0.1));
+    }
+
+    #[test]
+    fn full_circles_are_allowed() {
+        for &sweep in &[Angle::two_pi(), -Angle::two_pi()] {
+            let circle = Arc::from_centre_radius(
+                Point::new(1.0, 2.0),
+                3.0,
+                Angle::frac_pi_4(),
+                sweep,
+            );
+
+            assert!(circle.start().approx_eq(&circle.end()));
+            assert!(circle.is_major_arc());
+            for degrees in (0..360).step_by(15) {
+                assert!(circle.contains_angle(Angle::degrees(degrees as f64)));
+            }
+        }
+    }
+
+    #[test]
+    fn mid_point_and_sagitta() {
+        let arc: Arc<UnknownUnit> = Arc::from_centre_radius(
+            Point::new(1.0, 1.0),
+            2.0,
+            Angle::frac_pi_2(),
+            -Angle::pi(),
+        );
+
+        assert!(arc.mid_point().approx_eq(&Point::new(3.0, 1.0)));
+        assert!(arc.sagitta().approx_eq(&2.0));
+
+        let quarter: Arc<UnknownUnit> = Arc::from_centre_radius(
+            Point::zero(),
+            1.0,
+            Angle::zero(),
+            Angle::frac_pi_2(),
+        );
+        let chord_mid_point = quarter.start().lerp(quarter.end(), 0.5);
+
+        assert!(quarter
+            .sagitta()
+            .approx_eq(&quarter.mid_point().distance_to(chord_mid_point)));
+    }
 }
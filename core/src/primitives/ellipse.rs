@@ -0,0 +1,270 @@
+use crate::{angle_in_range, primitives::Arc, Angle};
+use euclid::{approxeq::ApproxEq, Point2D, Vector2D};
+
+/// An ellipse, possibly rotated.
+///
+/// Points on the ellipse are found using the *parametric* angle (sometimes
+/// called the eccentric anomaly), so the point at angle `t` is
+///
+/// ```text
+/// centre + rotate(rotation, (major_radius * cos(t), minor_radius * sin(t)))
+/// ```
+///
+/// This is the same convention used by [`kurbo::Arc`][kurbo] and SVG. Unless
+/// the ellipse is a circle, the parametric angle isn't the same as the angle
+/// between the x-axis and a line from the centre to the point.
+///
+/// [kurbo]: https://docs.rs/kurbo/0.6/kurbo/struct.Arc.html
+#[derive(Debug, PartialEq)]
+pub struct Ellipse<S> {
+    centre: Point2D<f64, S>,
+    major_radius: f64,
+    minor_radius: f64,
+    rotation: Angle,
+}
+
+impl<S> Ellipse<S> {
+    /// Create a new [`Ellipse`], where `rotation` is the angle between the
+    /// x-axis and the major axis.
+    pub fn new(
+        centre: Point2D<f64, S>,
+        major_radius: f64,
+        minor_radius: f64,
+        rotation: Angle,
+    ) -> Self {
+        debug_assert!(minor_radius > 0.0);
+        debug_assert!(major_radius >= minor_radius);
+
+        Ellipse {
+            centre,
+            major_radius,
+            minor_radius,
+            rotation,
+        }
+    }
+
+    /// The [`Ellipse`]'s centre point.
+    pub const fn centre(self) -> Point2D<f64, S> { self.centre }
+
+    /// Half the length of the [`Ellipse`]'s longest diameter.
+    pub const fn major_radius(self) -> f64 { self.major_radius }
+
+    /// Half the length of the [`Ellipse`]'s shortest diameter.
+    pub const fn minor_radius(self) -> f64 { self.minor_radius }
+
+    /// The angle between the x-axis and the major axis.
+    pub const fn rotation(self) -> Angle { self.rotation }
+
+    /// Is this [`Ellipse`] actually a circle?
+    pub fn is_circle(self) -> bool {
+        self.major_radius.approx_eq(&self.minor_radius)
+    }
+
+    /// Get the point at a particular parametric angle.
+    pub fn point_at(self, angle: Angle) -> Point2D<f64, S> {
+        let (sin, cos) = angle.sin_cos();
+        let (rot_sin, rot_cos) = self.rotation.sin_cos();
+        let x = self.major_radius * cos;
+        let y = self.minor_radius * sin;
+
+        self.centre
+            + Vector2D::new(
+                x * rot_cos - y * rot_sin,
+                x * rot_sin + y * rot_cos,
+            )
+    }
+
+    /// The derivative of [`Ellipse::point_at()`] with respect to the
+    /// parametric angle.
+    pub(crate) fn tangent_at(self, angle: Angle) -> Vector2D<f64, S> {
+        let (sin, cos) = angle.sin_cos();
+        let (rot_sin, rot_cos) = self.rotation.sin_cos();
+        let x = -self.major_radius * sin;
+        let y = self.minor_radius * cos;
+
+        Vector2D::new(x * rot_cos - y * rot_sin, x * rot_sin + y * rot_cos)
+    }
+}
+
+impl<S> ApproxEq<f64> for Ellipse<S> {
+    fn approx_epsilon() -> f64 { f64::approx_epsilon() }
+
+    fn approx_eq_eps(&self, other: &Self, approx_epsilon: &f64) -> bool {
+        let eps = Point2D::new(*approx_epsilon, *approx_epsilon);
+
+        self.centre.approx_eq_eps(&other.centre, &eps)
+            && self
+                .major_radius
+                .approx_eq_eps(&other.major_radius, approx_epsilon)
+            && self
+                .minor_radius
+                .approx_eq_eps(&other.minor_radius, approx_epsilon)
+            && self
+                .rotation
+                .radians
+                .approx_eq_eps(&other.rotation.radians, approx_epsilon)
+    }
+}
+
+impl<S> Copy for Ellipse<S> {}
+
+impl<S> Clone for Ellipse<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+/// A segment of an [`Ellipse`].
+///
+/// The start and sweep are parametric angles (see [`Ellipse`]). A positive
+/// sweep goes anti-clockwise.
+#[derive(Debug, PartialEq)]
+pub struct EllipticalArc<S> {
+    ellipse: Ellipse<S>,
+    start_angle: Angle,
+    sweep_angle: Angle,
+}
+
+impl<S> EllipticalArc<S> {
+    /// Create a new [`EllipticalArc`].
+    pub const fn new(
+        ellipse: Ellipse<S>,
+        start_angle: Angle,
+        sweep_angle: Angle,
+    ) -> Self {
+        EllipticalArc {
+            ellipse,
+            start_angle,
+            sweep_angle,
+        }
+    }
+
+    /// An [`EllipticalArc`] which goes all the way around the [`Ellipse`].
+    pub fn full(ellipse: Ellipse<S>) -> Self {
+        EllipticalArc::new(ellipse, Angle::zero(), Angle::two_pi())
+    }
+
+    /// The [`Ellipse`] this arc lies on.
+    pub const fn ellipse(self) -> Ellipse<S> { self.ellipse }
+
+    /// The [`EllipticalArc`]'s centre point.
+    pub const fn centre(self) -> Point2D<f64, S> { self.ellipse.centre }
+
+    /// The parametric angle the arc starts at.
+    pub const fn start_angle(self) -> Angle { self.start_angle }
+
+    /// How far the arc sweeps, where positive is anti-clockwise.
+    pub const fn sweep_angle(self) -> Angle { self.sweep_angle }
+
+    /// The parametric angle the arc ends at.
+    pub fn end_angle(self) -> Angle { self.start_angle() + self.sweep_angle() }
+
+    /// Does the arc sweep anti-clockwise?
+    pub fn is_anticlockwise(self) -> bool { self.sweep_angle > Angle::zero() }
+
+    /// Does the arc sweep clockwise?
+    pub fn is_clockwise(self) -> bool { self.sweep_angle < Angle::zero() }
+
+    /// Does this go all the way around the [`Ellipse`]?
+    pub fn is_full(self) -> bool {
+        self.sweep_angle.radians.abs() >= Angle::two_pi().radians
+    }
+
+    /// The arc's first point.
+    pub fn start(self) -> Point2D<f64, S> { self.point_at(Angle::zero()) }
+
+    /// The arc's last point.
+    pub fn end(self) -> Point2D<f64, S> { self.point_at(self.sweep_angle()) }
+
+    /// Get the point `angle` radians (parametric) along from the start.
+    pub fn point_at(self, angle: Angle) -> Point2D<f64, S> {
+        self.ellipse.point_at(self.start_angle() + angle)
+    }
+
+    /// Does the [`EllipticalArc`] sweep through a particular parametric angle?
+    pub fn contains_angle(self, angle: Angle) -> bool {
+        angle_in_range(angle, self.start_angle(), self.sweep_angle())
+    }
+
+    /// Get an [`EllipticalArc`] covering the same points, but sweeping in the
+    /// opposite direction.
+    pub fn reverse(self) -> Self {
+        EllipticalArc::new(self.ellipse, self.end_angle(), -self.sweep_angle())
+    }
+}
+
+/// Every [`Arc`] is an [`EllipticalArc`] whose radii are equal.
+///
+/// This is useful when an [`Arc`] needs to be scaled non-uniformly, because
+/// an [`EllipticalArc`] can represent the result exactly.
+impl<S> From<Arc<S>> for EllipticalArc<S> {
+    fn from(arc: Arc<S>) -> Self {
+        let ellipse = Ellipse::new(
+            arc.centre(),
+            arc.radius(),
+            arc.radius(),
+            Angle::zero(),
+        );
+
+        EllipticalArc::new(ellipse, arc.start_angle(), arc.sweep_angle())
+    }
+}
+
+impl<S> ApproxEq<f64> for EllipticalArc<S> {
+    fn approx_epsilon() -> f64 { f64::approx_epsilon() }
+
+    fn approx_eq_eps(&self, other: &Self, approx_epsilon: &f64) -> bool {
+        self.ellipse.approx_eq_eps(&other.ellipse, approx_epsilon)
+            && self
+                .start_angle
+                .radians
+                .approx_eq_eps(&other.start_angle.radians, approx_epsilon)
+            && self
+                .sweep_angle
+                .radians
+                .approx_eq_eps(&other.sweep_angle.radians, approx_epsilon)
+    }
+}
+
+impl<S> Copy for EllipticalArc<S> {}
+
+impl<S> Clone for EllipticalArc<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    #[test]
+    fn points_on_a_rotated_ellipse() {
+        let ellipse =
+            Ellipse::new(Point::new(1.0, 1.0), 2.0, 1.0, Angle::frac_pi_2());
+
+        assert!(ellipse
+            .point_at(Angle::zero())
+            .approx_eq(&Point::new(1.0, 3.0)));
+        assert!(ellipse
+            .point_at(Angle::frac_pi_2())
+            .approx_eq(&Point::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn an_arc_is_a_circular_elliptical_arc() {
+        let arc = Arc::from_centre_radius(
+            Point::new(1.0, 2.0),
+            3.0,
+            Angle::degrees(30.0),
+            Angle::degrees(-100.0),
+        );
+
+        let got = EllipticalArc::from(arc);
+
+        assert!(got.ellipse().is_circle());
+        assert!(got.start().approx_eq(&arc.start()));
+        assert!(got.end().approx_eq(&arc.end()));
+        assert!(got
+            .point_at(Angle::degrees(-40.0))
+            .approx_eq(&arc.point_at(Angle::degrees(-40.0))));
+    }
+}
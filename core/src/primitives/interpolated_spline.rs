@@ -0,0 +1,289 @@
+use arrayvec::ArrayVec;
+use euclid::{approxeq::ApproxEq, Point2D, Vector2D};
+
+/// A smooth curve which passes through each of its control points.
+///
+/// Internally the curve is made of one cubic Bézier segment between each
+/// pair of neighbouring control points.
+#[derive(Debug, PartialEq)]
+pub struct InterpolatedSpline<S> {
+    points: Vec<Point2D<f64, S>>,
+    segments: Vec<Cubic<S>>,
+}
+
+impl<S> InterpolatedSpline<S> {
+    /// Create a [Catmull-Rom spline][wiki] which passes through every point.
+    ///
+    /// The `alpha` controls how the curve is parameterised. It is normally
+    /// between `0.0` and `1.0`, where `0.0` is the *uniform* Catmull-Rom
+    /// spline, `0.5` is *centripetal* and `1.0` is *chordal*. Centripetal is
+    /// usually what you want because it never forms cusps or loops within a
+    /// segment.
+    ///
+    /// Consecutive duplicate points are ignored, and you will get `None` if
+    /// there are no points at all.
+    ///
+    /// [wiki]: https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline
+    pub fn catmull_rom(points: &[Point2D<f64, S>], alpha: f64) -> Option<Self> {
+        let mut points = points.to_vec();
+        points.dedup();
+
+        if points.is_empty() {
+            return None;
+        }
+
+        let segments = (1..points.len())
+            .map(|i| {
+                let p1 = points[i - 1];
+                let p2 = points[i];
+                // mirror the neighbouring point when we're at either end
+                let p0 = if i >= 2 {
+                    points[i - 2]
+                } else {
+                    p1 + (p1 - p2)
+                };
+                let p3 = points.get(i + 1).copied().unwrap_or(p2 + (p2 - p1));
+
+                Cubic::catmull_rom(p0, p1, p2, p3, alpha)
+            })
+            .collect();
+
+        Some(InterpolatedSpline { points, segments })
+    }
+
+    /// The points this [`InterpolatedSpline`] passes through.
+    pub fn points(&self) -> &[Point2D<f64, S>] { &self.points }
+
+    /// The first point on the curve.
+    pub fn start(&self) -> Point2D<f64, S> { self.points[0] }
+
+    /// The last point on the curve.
+    pub fn end(&self) -> Point2D<f64, S> { self.points[self.points.len() - 1] }
+
+    /// Sample the curve, where `t` goes from `0.0` at [`Self::start()`] to
+    /// `1.0` at [`Self::end()`].
+    ///
+    /// Each segment takes an equal share of `t`, so the `i`'th point is at
+    /// `i / (points.len() - 1)`. Values outside `0.0..=1.0` are clamped.
+    pub fn point_at(&self, t: f64) -> Point2D<f64, S> {
+        if self.segments.is_empty() {
+            return self.start();
+        }
+
+        let t = t.clamp(0.0, 1.0) * self.segments.len() as f64;
+        let index = (t.floor() as usize).min(self.segments.len() - 1);
+
+        self.segments[index].point_at(t - index as f64)
+    }
+
+    /// Every point which may be on the edge of the curve's bounding box.
+    pub(crate) fn extremes(
+        &self,
+    ) -> impl Iterator<Item = Point2D<f64, S>> + '_ {
+        self.points
+            .iter()
+            .copied()
+            .chain(self.segments.iter().flat_map(|s| s.turning_points()))
+    }
+}
+
+impl<S> ApproxEq<f64> for InterpolatedSpline<S> {
+    fn approx_epsilon() -> f64 { f64::approx_epsilon() }
+
+    fn approx_eq_eps(&self, other: &Self, approx_epsilon: &f64) -> bool {
+        let eps = Point2D::new(*approx_epsilon, *approx_epsilon);
+
+        self.segments.len() == other.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(&other.segments)
+                .all(|(left, right)| {
+                    left.0
+                        .iter()
+                        .zip(&right.0)
+                        .all(|(l, r)| l.approx_eq_eps(r, &eps))
+                })
+    }
+}
+
+impl<S> Clone for InterpolatedSpline<S> {
+    fn clone(&self) -> Self {
+        InterpolatedSpline {
+            points: self.points.clone(),
+            segments: self.segments.clone(),
+        }
+    }
+}
+
+/// A cubic Bézier segment.
+#[derive(Debug, PartialEq)]
+struct Cubic<S>([Point2D<f64, S>; 4]);
+
+impl<S> Cubic<S> {
+    /// The Catmull-Rom segment between `p1` and `p2`, converted to a Bézier.
+    fn catmull_rom(
+        p0: Point2D<f64, S>,
+        p1: Point2D<f64, S>,
+        p2: Point2D<f64, S>,
+        p3: Point2D<f64, S>,
+        alpha: f64,
+    ) -> Self {
+        let knot = |a: Point2D<f64, S>, b: Point2D<f64, S>| {
+            (b - a).length().powf(alpha)
+        };
+        let dt0 = knot(p0, p1);
+        let dt1 = knot(p1, p2);
+        let dt2 = knot(p2, p3);
+
+        // tangents at p1 and p2, scaled to the [0, 1] range of this segment
+        let m1: Vector2D<f64, S> =
+            ((p1 - p0) / dt0 - (p2 - p0) / (dt0 + dt1) + (p2 - p1) / dt1) * dt1;
+        let m2: Vector2D<f64, S> =
+            ((p2 - p1) / dt1 - (p3 - p1) / (dt1 + dt2) + (p3 - p2) / dt2) * dt1;
+
+        Cubic([p1, p1 + m1 / 3.0, p2 - m2 / 3.0, p2])
+    }
+
+    fn point_at(&self, t: f64) -> Point2D<f64, S> {
+        let [b0, b1, b2, b3] = self.0;
+        let u = 1.0 - t;
+        let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+
+        let (x, y) = [b0, b1, b2, b3].iter().zip(&weights).fold(
+            (0.0, 0.0),
+            |(x, y), (point, weight)| {
+                (x + point.x * weight, y + point.y * weight)
+            },
+        );
+
+        Point2D::new(x, y)
+    }
+
+    /// The points where the segment stops moving horizontally or vertically.
+    fn turning_points(&self) -> ArrayVec<[Point2D<f64, S>; 4]> {
+        let [b0, b1, b2, b3] = self.0;
+        let xs = derivative_roots(b1.x - b0.x, b2.x - b1.x, b3.x - b2.x);
+        let ys = derivative_roots(b1.y - b0.y, b2.y - b1.y, b3.y - b2.y);
+
+        xs.iter()
+            .chain(&ys)
+            .flatten()
+            .map(|&t| self.point_at(t))
+            .collect()
+    }
+}
+
+impl<S> Copy for Cubic<S> {}
+
+impl<S> Clone for Cubic<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+/// Find where the derivative of a 1D cubic Bézier is zero, given the
+/// differences between its control points.
+///
+/// Only roots strictly inside the segment are returned.
+fn derivative_roots(d0: f64, d1: f64, d2: f64) -> [Option<f64>; 2] {
+    // B'(t)/3 = (1-t)²·d0 + 2(1-t)t·d1 + t²·d2
+    let a = d0 - 2.0 * d1 + d2;
+    let b = 2.0 * (d1 - d0);
+    let c = d0;
+    let inside = |t: f64| Some(t).filter(|t| *t > 0.0 && *t < 1.0);
+
+    if a.abs() < f64::EPSILON {
+        if b.abs() < f64::EPSILON {
+            [None, None]
+        } else {
+            [inside(-c / b), None]
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            [None, None]
+        } else {
+            let root = discriminant.sqrt();
+            [
+                inside((-b + root) / (2.0 * a)),
+                inside((-b - root) / (2.0 * a)),
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = euclid::default::Point2D<f64>;
+
+    fn points() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 0.0),
+            Point::new(6.0, -1.0),
+        ]
+    }
+
+    #[test]
+    fn the_curve_passes_through_every_point() {
+        let points = points();
+
+        for &alpha in &[0.0, 0.5, 1.0] {
+            let spline =
+                InterpolatedSpline::catmull_rom(&points, alpha).unwrap();
+
+            for (i, point) in points.iter().enumerate() {
+                let t = i as f64 / (points.len() - 1) as f64;
+                assert!(spline.point_at(t).approx_eq(point), "{} {}", alpha, i);
+            }
+        }
+    }
+
+    #[test]
+    fn segments_join_smoothly() {
+        let spline = InterpolatedSpline::catmull_rom(&points(), 0.5).unwrap();
+
+        for pair in spline.segments.windows(2) {
+            let incoming = pair[0].0[3] - pair[0].0[2];
+            let outgoing = pair[1].0[1] - pair[1].0[0];
+
+            assert!(incoming.cross(outgoing).abs() < 1e-10);
+            assert!(incoming.dot(outgoing) > 0.0);
+        }
+    }
+
+    #[test]
+    fn collinear_points_make_a_straight_line() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(3.0, 3.0),
+        ];
+        let spline = InterpolatedSpline::catmull_rom(&points, 0.5).unwrap();
+
+        for i in 0..=10 {
+            let point = spline.point_at(i as f64 / 10.0);
+            assert!(point.x.approx_eq(&point.y), "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn degenerate_splines() {
+        assert!(InterpolatedSpline::<euclid::UnknownUnit>::catmull_rom(
+            &[],
+            0.5
+        )
+        .is_none());
+
+        let point = Point::new(1.0, 2.0);
+        let spline =
+            InterpolatedSpline::catmull_rom(&[point, point], 0.5).unwrap();
+
+        assert_eq!(spline.points(), &[point]);
+        assert_eq!(spline.point_at(0.3), point);
+    }
+}
@@ -1,4 +1,5 @@
-use euclid::{Length, Point2D, Vector2D};
+use crate::Angle;
+use euclid::{approxeq::ApproxEq, Length, Point2D, Vector2D};
 
 /// A line connecting [`Line::start`] to [`Line::end`].
 #[derive(Debug, Default, PartialEq)]
@@ -19,16 +20,95 @@ impl<S> Line<S> {
     pub fn displacement(&self) -> Vector2D<f64, S> { self.end - self.start }
 
     /// The [`Line::displacement()`], normalised to a unit vector.
+    ///
+    /// A zero-length [`Line`] has no direction, so this will return the zero
+    /// vector.
     pub fn direction(&self) -> Vector2D<f64, S> {
-        self.displacement().normalize()
+        let displacement = self.displacement();
+
+        if displacement == Vector2D::zero() {
+            displacement
+        } else {
+            displacement.normalize()
+        }
+    }
+
+    /// The point halfway between [`Line::start`] and [`Line::end`].
+    pub fn midpoint(&self) -> Point2D<f64, S> { self.start.lerp(self.end, 0.5) }
+
+    /// The angle between the positive x-axis and this [`Line`].
+    pub fn angle(&self) -> Angle {
+        // Note: Vector2D::angle_from_x_axis() uses a fast approximation of
+        // atan2 which returns NaN for the zero vector
+        let Vector2D { x, y, .. } = self.displacement();
+        Angle::radians(y.atan2(x))
     }
 
     /// The [`Line`]'s length.
     pub fn length(self) -> f64 { self.displacement().length() }
 
-    ///  How close would the [`Point2D`] get if this line were extended
+    /// Get a [`Line`] which crosses this one at right angles through its
+    /// [`Line::midpoint()`], extending `half_length` either side.
+    ///
+    /// A zero-length [`Line`] has no direction, so there is no perpendicular
+    /// bisector.
+    pub fn perpendicular_bisector(
+        &self,
+        half_length: Length<f64, S>,
+    ) -> Option<Line<S>> {
+        let direction = self.direction();
+
+        if direction == Vector2D::zero() {
+            return None;
+        }
+
+        let normal = Vector2D::new(-direction.y, direction.x);
+        let midpoint = self.midpoint();
+
+        Some(Line::new(
+            midpoint - normal * half_length.get(),
+            midpoint + normal * half_length.get(),
+        ))
+    }
+
+    /// Keep the [`Line::start`] and move the [`Line::end`] to `target`.
+    ///
+    /// This is normally used to extend (or trim) a [`Line`] to an intersection
+    /// point, so `target` is assumed to lie on the [`Line`]'s infinite
+    /// extension.
+    pub fn extend_to(&self, target: Point2D<f64, S>) -> Line<S> {
+        Line::new(self.start, target)
+    }
+
+    /// Lengthen the [`Line`] by moving [`Line::end`] `length` further along
+    /// its [`Line::direction()`].
+    ///
+    /// A negative `length` will shorten the [`Line`], and a zero-length
+    /// [`Line`] has no direction so it is returned unchanged.
+    pub fn extended_by(&self, length: Length<f64, S>) -> Line<S> {
+        self.extend_to(self.end + self.direction() * length.get())
+    }
+
+    /// How close would the [`Point2D`] get if this line were extended
     /// forever?
     ///
+    /// This measures to the *infinite* line, so points past either end are
+    /// still measured perpendicular to it. Use
+    /// [`crate::algorithms::ClosestPoint`] if you need the distance to the
+    /// segment itself. A zero-length [`Line`] has no direction, so the
+    /// distance to [`Line::start`] is used instead.
+    ///
+    /// ```rust
+    /// # use arcs_core::primitives::Line;
+    /// # type Point = euclid::default::Point2D<f64>;
+    /// let line = Line::new(Point::zero(), Point::new(10.0, 0.0));
+    ///
+    /// // directly above the line
+    /// assert_eq!(line.perpendicular_distance_to(Point::new(5.0, 3.0)).get(), 3.0);
+    /// // past the end of the segment, but still 3 units from the infinite line
+    /// assert_eq!(line.perpendicular_distance_to(Point::new(20.0, -3.0)).get(), 3.0);
+    /// ```
+    ///
     /// See also [*Distance from a point to a line*][wiki] on Wikipedia.
     ///
     /// [wiki]: https://en.wikipedia.org/wiki/Distance_from_a_point_to_a_line
@@ -36,7 +116,7 @@ impl<S> Line<S> {
         self,
         point: Point2D<f64, S>,
     ) -> Length<f64, S> {
-        const SOME_SMALL_NUMBER: f64 = std::f64::EPSILON * 100.0;
+        const SOME_SMALL_NUMBER: f64 = f64::EPSILON * 100.0;
 
         let side_a = self.start - point;
         let side_b = self.end - point;
@@ -51,6 +131,70 @@ impl<S> Line<S> {
             area.abs() * 2.0 / base_length
         })
     }
+
+    /// If the two [`Line`]s are collinear, get the portion they share.
+    ///
+    /// Both of `other`'s ends must be within `tolerance` of this [`Line`]'s
+    /// infinite extension, and the shared portion must be longer than
+    /// `tolerance`, so lines which only touch end-to-end don't count. The
+    /// result runs in the same direction as `self`.
+    ///
+    /// A zero-length [`Line`] has no direction, so it never overlaps.
+    ///
+    /// ```rust
+    /// # use arcs_core::primitives::Line;
+    /// # type Point = euclid::default::Point2D<f64>;
+    /// let line = Line::new(Point::zero(), Point::new(10.0, 0.0));
+    /// let other = Line::new(Point::new(15.0, 0.0), Point::new(5.0, 0.0));
+    ///
+    /// let overlap = line.collinear_overlap(&other, 1e-6).unwrap();
+    ///
+    /// assert_eq!(overlap, Line::new(Point::new(5.0, 0.0), Point::new(10.0, 0.0)));
+    /// ```
+    pub fn collinear_overlap(
+        &self,
+        other: &Line<S>,
+        tolerance: f64,
+    ) -> Option<Line<S>> {
+        let direction = self.direction();
+
+        if direction == Vector2D::zero() || other.start == other.end {
+            return None;
+        }
+
+        let on_this_line =
+            |point| self.perpendicular_distance_to(point).get() <= tolerance;
+
+        if !on_this_line(other.start) || !on_this_line(other.end) {
+            return None;
+        }
+
+        // project everything onto our direction, with our start at 0
+        let a = (other.start - self.start).dot(direction);
+        let b = (other.end - self.start).dot(direction);
+        let lower = a.min(b).max(0.0);
+        let upper = a.max(b).min(self.length());
+
+        if upper - lower <= tolerance {
+            return None;
+        }
+
+        Some(Line::new(
+            self.start + direction * lower,
+            self.start + direction * upper,
+        ))
+    }
+}
+
+impl<S> ApproxEq<f64> for Line<S> {
+    fn approx_epsilon() -> f64 { f64::approx_epsilon() }
+
+    fn approx_eq_eps(&self, other: &Self, approx_epsilon: &f64) -> bool {
+        let eps = Point2D::new(*approx_epsilon, *approx_epsilon);
+
+        self.start.approx_eq_eps(&other.start, &eps)
+            && self.end.approx_eq_eps(&other.end, &eps)
+    }
 }
 
 impl<S> Copy for Line<S> {}
@@ -75,4 +219,144 @@ mod tests {
         assert_eq!(v.length(), 5.0);
         assert_eq!(v.displacement(), displacement);
     }
+
+    #[test]
+    fn basic_properties() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(4.0, 5.0));
+
+        assert_eq!(line.length(), 5.0);
+        assert_eq!(line.midpoint(), Point::new(2.5, 3.0));
+        assert_eq!(line.direction(), Vector::new(0.6, 0.8));
+        assert!(line
+            .angle()
+            .approx_eq(&Angle::radians(f64::atan2(4.0, 3.0))));
+    }
+
+    #[test]
+    fn zero_length_line_has_no_direction() {
+        let point = Point::new(1.0, 2.0);
+        let line = Line::new(point, point);
+
+        assert_eq!(line.direction(), Vector::zero());
+        assert_eq!(line.midpoint(), point);
+        assert_eq!(line.angle(), Angle::zero());
+    }
+
+    #[test]
+    fn perpendicular_bisector_of_a_horizontal_line() {
+        let line = Line::new(Point::new(0.0, 1.0), Point::new(4.0, 1.0));
+
+        let got = line.perpendicular_bisector(Length::new(2.0)).unwrap();
+
+        assert_eq!(got, Line::new(Point::new(2.0, -1.0), Point::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn zero_length_line_has_no_perpendicular_bisector() {
+        let point = Point::new(1.0, 2.0);
+        let line = Line::new(point, point);
+
+        assert!(line.perpendicular_bisector(Length::new(1.0)).is_none());
+    }
+
+    #[test]
+    fn extend_to_a_point() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        let target = Point::new(5.0, 5.0);
+
+        let got = line.extend_to(target);
+
+        assert_eq!(got, Line::new(line.start, target));
+    }
+
+    #[test]
+    fn extend_by_a_length() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(4.0, 5.0));
+
+        let got = line.extended_by(Length::new(5.0));
+
+        assert_eq!(got.start, line.start);
+        assert!(got.end.approx_eq(&Point::new(7.0, 9.0)));
+        assert!(got.length().approx_eq(&10.0));
+    }
+
+    #[test]
+    fn extending_a_zero_length_line_does_nothing() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0));
+
+        let got = line.extended_by(Length::new(5.0));
+
+        assert_eq!(got, line);
+    }
+
+    #[test]
+    fn approximately_equal_lines() {
+        let line = Line::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0));
+        let nudged = Line::new(line.start, line.end + Vector::new(1e-9, 0.0));
+        let moved = Line::new(line.start, line.end + Vector::new(0.1, 0.0));
+
+        assert!(line.approx_eq(&nudged));
+        assert!(!line.approx_eq(&moved));
+        assert!(line.approx_eq_eps(&moved, &0.5));
+    }
+
+    #[test]
+    fn overlap_of_collinear_lines() {
+        let line = Line::new(Point::new(1.0, 1.0), Point::new(5.0, 5.0));
+        let other = Line::new(Point::new(3.0, 3.0), Point::new(8.0, 8.0));
+
+        let got = line.collinear_overlap(&other, 1e-6).unwrap();
+
+        assert!(got.approx_eq(&Line::new(other.start, line.end)));
+        // the overlap always follows our own direction
+        let reversed = Line::new(other.end, other.start);
+        let got = reversed.collinear_overlap(&line, 1e-6).unwrap();
+        assert!(got.approx_eq(&Line::new(line.end, other.start)));
+    }
+
+    #[test]
+    fn one_line_inside_another() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+        let inner = Line::new(Point::new(2.0, 0.0), Point::new(4.0, 0.0));
+
+        assert!(line
+            .collinear_overlap(&inner, 1e-6)
+            .unwrap()
+            .approx_eq(&inner));
+        assert!(inner
+            .collinear_overlap(&line, 1e-6)
+            .unwrap()
+            .approx_eq(&inner));
+    }
+
+    #[test]
+    fn lines_which_dont_overlap() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+        let parallel = Line::new(Point::new(0.0, 1.0), Point::new(10.0, 1.0));
+        let crossing = Line::new(Point::new(5.0, -5.0), Point::new(5.0, 5.0));
+        let disjoint = Line::new(Point::new(11.0, 0.0), Point::new(20.0, 0.0));
+        let touching = Line::new(Point::new(10.0, 0.0), Point::new(20.0, 0.0));
+        let zero_length = Line::new(Point::new(5.0, 0.0), Point::new(5.0, 0.0));
+
+        for other in &[parallel, crossing, disjoint, touching, zero_length] {
+            assert!(
+                line.collinear_overlap(other, 1e-6).is_none(),
+                "{:?}",
+                other
+            );
+        }
+    }
+
+    #[test]
+    fn overlap_within_tolerance() {
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+        let nearly = Line::new(Point::new(5.0, 0.01), Point::new(15.0, -0.01));
+
+        assert!(line.collinear_overlap(&nearly, 0.001).is_none());
+        let got = line.collinear_overlap(&nearly, 0.1).unwrap();
+        assert!(got.approx_eq_eps(
+            &Line::new(Point::new(5.0, 0.0), Point::new(10.0, 0.0)),
+            &1e-6
+        ));
+    }
 }
@@ -1,7 +1,11 @@
 //! Basic geometric types which are generic over their coordinate space.
 
 mod arc;
+mod ellipse;
+mod interpolated_spline;
 mod line;
 
-pub use arc::Arc;
+pub use arc::{Arc, ArcError};
+pub use ellipse::{Ellipse, EllipticalArc};
+pub use interpolated_spline::InterpolatedSpline;
 pub use line::Line;
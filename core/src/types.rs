@@ -0,0 +1,190 @@
+use std::f64::consts::PI;
+
+/// A strongly-typed angle, useful for dealing with the pesky modular arithmetic
+/// normally associated with circles and angles.
+pub type Angle = euclid::Angle<f64>;
+
+/// Wrap an [`Angle`] into the range `[0, 2π)`.
+pub fn normalize_angle(angle: Angle) -> Angle {
+    let radians = angle.radians.rem_euclid(2.0 * PI);
+
+    // rem_euclid() can round up to exactly 2π for tiny negative angles
+    if radians >= 2.0 * PI {
+        Angle::zero()
+    } else {
+        Angle::radians(radians)
+    }
+}
+
+/// Would you pass through `angle` when sweeping `sweep` radians from `start`?
+///
+/// Positive sweeps go anti-clockwise and negative sweeps go clockwise. Angles
+/// are compared modulo a full revolution, so ranges which cross the `0°` or
+/// `180°` seams are handled correctly.
+pub fn angle_in_range(angle: Angle, start: Angle, sweep: Angle) -> bool {
+    let sweep = sweep.radians;
+
+    if sweep.abs() >= 2.0 * PI {
+        return true;
+    }
+
+    // how far we need to travel from the start (in the direction of the
+    // sweep) to reach the angle
+    let offset = normalize_angle((angle - start) * sweep.signum());
+
+    offset.radians <= sweep.abs()
+}
+
+/// The shortest signed rotation which takes you from `a` to `b`.
+///
+/// The result is always in the range `(-π, π]`, where positive is
+/// anti-clockwise. Angles exactly opposite each other are `π` apart.
+///
+/// ```rust
+/// # use arcs_core::{angle_between, Angle};
+/// # use euclid::approxeq::ApproxEq;
+/// let got = angle_between(Angle::degrees(350.0), Angle::degrees(10.0));
+/// assert!(got.approx_eq(&Angle::degrees(20.0)));
+///
+/// let got = angle_between(Angle::degrees(10.0), Angle::degrees(350.0));
+/// assert!(got.approx_eq(&Angle::degrees(-20.0)));
+/// ```
+pub fn angle_between(a: Angle, b: Angle) -> Angle {
+    let difference = normalize_angle(b - a).radians;
+
+    if difference > PI {
+        Angle::radians(difference - 2.0 * PI)
+    } else {
+        Angle::radians(difference)
+    }
+}
+
+/// The angle halfway between `a` and `b`, going the shortest way around
+/// (see [`angle_between()`]).
+///
+/// The result is normalized to `[0, 2π)`.
+pub fn bisect(a: Angle, b: Angle) -> Angle {
+    normalize_angle(a + angle_between(a, b) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    #[test]
+    fn normalize_angles() {
+        let inputs: Vec<(f64, f64)> = vec![
+            (0.0, 0.0),
+            (90.0, 90.0),
+            (360.0, 0.0),
+            (-90.0, 270.0),
+            (-180.0, 180.0),
+            (720.0 + 45.0, 45.0),
+        ];
+
+        for (input, expected) in inputs {
+            let got = normalize_angle(Angle::degrees(input));
+
+            assert!(
+                got.radians.approx_eq(&expected.to_radians()),
+                "{}° should normalize to {}°, not {}°",
+                input,
+                expected,
+                got.to_degrees()
+            );
+        }
+    }
+
+    #[test]
+    fn tiny_negative_angles_stay_below_a_full_revolution() {
+        let got = normalize_angle(Angle::radians(-1e-20));
+
+        assert!(got.radians < 2.0 * PI);
+    }
+
+    #[test]
+    fn ranges_crossing_the_180_degree_seam() {
+        let start = Angle::degrees(170.0);
+        let anticlockwise = Angle::degrees(20.0);
+
+        assert!(angle_in_range(Angle::degrees(180.0), start, anticlockwise));
+        assert!(angle_in_range(Angle::degrees(-175.0), start, anticlockwise));
+        assert!(!angle_in_range(Angle::degrees(0.0), start, anticlockwise));
+
+        let start = Angle::degrees(-170.0);
+        let clockwise = Angle::degrees(-20.0);
+
+        assert!(angle_in_range(Angle::degrees(180.0), start, clockwise));
+        assert!(angle_in_range(Angle::degrees(175.0), start, clockwise));
+        assert!(!angle_in_range(Angle::degrees(0.0), start, clockwise));
+    }
+
+    #[test]
+    fn ranges_crossing_the_0_degree_seam() {
+        let start = Angle::degrees(-10.0);
+        let anticlockwise = Angle::degrees(20.0);
+
+        assert!(angle_in_range(Angle::degrees(0.0), start, anticlockwise));
+        assert!(angle_in_range(Angle::degrees(355.0), start, anticlockwise));
+        assert!(!angle_in_range(Angle::degrees(180.0), start, anticlockwise));
+
+        let start = Angle::degrees(10.0);
+        let clockwise = Angle::degrees(-20.0);
+
+        assert!(angle_in_range(Angle::degrees(0.0), start, clockwise));
+        assert!(angle_in_range(Angle::degrees(-5.0), start, clockwise));
+        assert!(!angle_in_range(Angle::degrees(180.0), start, clockwise));
+    }
+
+    #[test]
+    fn shortest_difference_between_angles() {
+        let inputs: Vec<(f64, f64, f64)> = vec![
+            (0.0, 90.0, 90.0),
+            (90.0, 0.0, -90.0),
+            (350.0, 10.0, 20.0),
+            (10.0, 350.0, -20.0),
+            (0.0, 180.0, 180.0),
+            (180.0, 0.0, 180.0),
+            (-90.0, 270.0, 0.0),
+            (45.0, 720.0 + 90.0, 45.0),
+        ];
+
+        for (a, b, expected) in inputs {
+            let got = angle_between(Angle::degrees(a), Angle::degrees(b));
+
+            assert!(
+                got.radians.approx_eq(&expected.to_radians()),
+                "{}° to {}° should be {}°, not {}°",
+                a,
+                b,
+                expected,
+                got.to_degrees()
+            );
+        }
+    }
+
+    #[test]
+    fn bisect_angles() {
+        let inputs: Vec<(f64, f64, f64)> = vec![
+            (0.0, 90.0, 45.0),
+            (90.0, 0.0, 45.0),
+            (350.0, 30.0, 10.0),
+            (30.0, 350.0, 10.0),
+            (0.0, 180.0, 90.0),
+        ];
+
+        for (a, b, expected) in inputs {
+            let got = bisect(Angle::degrees(a), Angle::degrees(b));
+
+            assert!(
+                got.radians.approx_eq(&expected.to_radians()),
+                "halfway between {}° and {}° should be {}°, not {}°",
+                a,
+                b,
+                expected,
+                got.to_degrees()
+            );
+        }
+    }
+}